@@ -26,6 +26,7 @@ mod targets {
 // Stub out `ParseError` to minimally support triple.rs and targets.rs.
 mod parse_error {
     #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
     pub enum ParseError {
         UnrecognizedArchitecture(String),
         UnrecognizedVendor(String),
@@ -33,6 +34,8 @@ mod parse_error {
         UnrecognizedEnvironment(String),
         UnrecognizedBinaryFormat(String),
         UnrecognizedField(String),
+        MissingEnvironmentVariable(String),
+        InvalidBytes,
     }
 }
 
@@ -70,6 +73,10 @@ fn write_host_rs(mut out: File, triple: Triple) -> io::Result<()> {
     writeln!(out, "use crate::Riscv64Architecture::*;")?;
     writeln!(out, "#[allow(unused_imports)]")?;
     writeln!(out, "use crate::CustomVendor;")?;
+    writeln!(out, "#[allow(unused_imports)]")?;
+    writeln!(out, "use crate::OsVersion;")?;
+    writeln!(out, "#[allow(unused_imports)]")?;
+    writeln!(out, "use crate::AmdGfxVersion;")?;
     writeln!(out)?;
     writeln!(out, "/// The `Triple` of the current host.")?;
     writeln!(out, "pub const HOST: Triple = Triple {{")?;
@@ -27,25 +27,34 @@ mod tests {
         assert_eq!(OperatingSystem::host(), OperatingSystem::Windows);
     }
 
+    #[test]
+    fn test_endianness() {
+        use super::*;
+        assert_eq!(
+            Architecture::host().endianness().unwrap(),
+            crate::Endianness::host()
+        );
+    }
+
     #[cfg(target_pointer_width = "16")]
     #[test]
     fn test_ptr16() {
         use super::*;
-        assert_eq!(Architecture::host().pointer_width().unwrap().bits(), 16);
+        assert_eq!(Triple::host().pointer_width().unwrap().bits(), 16);
     }
 
     #[cfg(target_pointer_width = "32")]
     #[test]
     fn test_ptr32() {
         use super::*;
-        assert_eq!(Architecture::host().pointer_width().unwrap().bits(), 32);
+        assert_eq!(Triple::host().pointer_width().unwrap().bits(), 32);
     }
 
     #[cfg(target_pointer_width = "64")]
     #[test]
     fn test_ptr64() {
         use super::*;
-        assert_eq!(Architecture::host().pointer_width().unwrap().bits(), 64);
+        assert_eq!(Triple::host().pointer_width().unwrap().bits(), 64);
     }
 
     #[test]
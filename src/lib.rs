@@ -2,18 +2,14 @@
 
 #![deny(missing_docs, trivial_numeric_casts, unused_extern_crates)]
 #![warn(unused_import_braces)]
-#![cfg_attr(
-    feature = "cargo-clippy",
-    warn(
-        clippy::float_arithmetic,
-        clippy::mut_mut,
-        clippy::nonminimal_bool,
-        clippy::option_map_unwrap_or,
-        clippy::option_map_unwrap_or_else,
-        clippy::print_stdout,
-        clippy::unicode_not_nfc,
-        clippy::use_self,
-    )
+#![warn(
+    clippy::float_arithmetic,
+    clippy::mut_mut,
+    clippy::nonminimal_bool,
+    clippy::map_unwrap_or,
+    clippy::print_stdout,
+    clippy::unicode_not_nfc,
+    clippy::use_self
 )]
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -22,6 +18,8 @@ extern crate alloc;
 mod data_model;
 mod host;
 mod parse_error;
+#[cfg(feature = "platforms")]
+mod platforms_compat;
 mod targets;
 #[macro_use]
 mod triple;
@@ -29,12 +27,19 @@ mod triple;
 pub use self::data_model::{CDataModel, Size};
 pub use self::host::HOST;
 pub use self::parse_error::ParseError;
+#[cfg(feature = "std")]
+pub use self::targets::register_custom_os;
 pub use self::targets::{
-    Aarch64Architecture, Architecture, ArmArchitecture, BinaryFormat, CustomVendor, Environment,
-    Mips32Architecture, Mips64Architecture, OperatingSystem, Riscv32Architecture,
-    Riscv64Architecture, Vendor, X86_32Architecture,
+    default_binary_format_for, Aarch64Architecture, AmdGfxVersion, Architecture,
+    ArchitectureFamily, ArmArchitecture, ArmProfile, ArmVersion, BinaryFormat, CustomVendor,
+    Environment, Mips32Architecture, Mips64Architecture, OperatingSystem, OsVersion,
+    Riscv32Architecture, Riscv64Architecture, Vendor, X86_32Architecture, KNOWN_TRIPLE_COUNT,
+};
+pub use self::triple::{
+    CallingConvention, DebugFormat, Endianness, FloatAbi, FramePointer, InconsistentTriple, Libc,
+    LinkerFlavor, PointerWidth, RiscvFloatAbi, SpelledTriple, SplitDebuginfo, TlsModel, Triple,
+    TripleField, UnwindStrategy, ValidationWarning, WindowsToolchain,
 };
-pub use self::triple::{CallingConvention, Endianness, PointerWidth, Triple};
 
 /// A simple wrapper around `Triple` that provides an implementation of
 /// `Default` which defaults to `Triple::host()`.
@@ -12,6 +12,12 @@ pub enum ParseError {
     UnrecognizedEnvironment(String),
     UnrecognizedBinaryFormat(String),
     UnrecognizedField(String),
+    /// An environment variable which was expected to hold a triple is unset
+    /// or isn't valid Unicode.
+    MissingEnvironmentVariable(String),
+    /// Bytes passed to `Triple::from_bytes` are truncated or weren't
+    /// produced by `Triple::to_bytes`.
+    InvalidBytes,
 }
 
 impl fmt::Display for ParseError {
@@ -26,6 +32,10 @@ impl fmt::Display for ParseError {
             UnrecognizedEnvironment(msg) => write!(fmt, "Unrecognized environment: {}", msg),
             UnrecognizedBinaryFormat(msg) => write!(fmt, "Unrecognized binary format: {}", msg),
             UnrecognizedField(msg) => write!(fmt, "Unrecognized field: {}", msg),
+            MissingEnvironmentVariable(var) => {
+                write!(fmt, "Missing environment variable: {}", var)
+            }
+            InvalidBytes => write!(fmt, "Invalid triple encoding"),
         }
     }
 }
@@ -0,0 +1,102 @@
+// This file provides conversions to and from the `platforms` crate's types,
+// enabled by the `platforms` feature.
+
+use crate::parse_error::ParseError;
+use crate::triple::Triple;
+use alloc::string::ToString;
+use core::convert::TryFrom;
+use core::str::FromStr;
+use platforms::{Arch, Env, Platform, OS};
+
+/// Parse a `platforms` entry's triple. This fails for the few triples rustc
+/// knows which this crate doesn't.
+impl TryFrom<&Platform> for Triple {
+    type Error = ParseError;
+
+    fn try_from(platform: &Platform) -> Result<Self, Self::Error> {
+        Self::from_str(platform.target_triple)
+    }
+}
+
+impl Triple {
+    /// Return the `platforms` crate's entry for this triple, or `None` if it
+    /// doesn't list this triple's string form.
+    ///
+    /// This is lossy where the two crates spell a target differently, as
+    /// with aliases and with Apple operating system versions, which rustc's
+    /// target names leave out.
+    pub fn to_platform(&self) -> Option<&'static Platform> {
+        Platform::find(&self.to_string())
+    }
+
+    /// Return this triple's `target_arch` as the `platforms` crate's `Arch`,
+    /// or `None` if it's unknown or `platforms` doesn't have it.
+    ///
+    /// Subarchitectures aren't represented, so `armv7` and `thumbv7em` are
+    /// both `Arch::Arm`.
+    pub fn platforms_arch(&self) -> Option<Arch> {
+        Arch::from_str(self.rustc_arch()?).ok()
+    }
+
+    /// Return this triple's `target_os` as the `platforms` crate's `OS`, or
+    /// `None` if `platforms` doesn't have it, as for custom operating
+    /// systems.
+    pub fn platforms_os(&self) -> Option<OS> {
+        OS::from_str(self.rustc_os()).ok()
+    }
+
+    /// Return this triple's `target_env` as the `platforms` crate's `Env`, or
+    /// `None` if `platforms` doesn't have it.
+    ///
+    /// `target_env` only names the C library, so ABI details such as the
+    /// `hf` in `gnueabihf` are lost.
+    pub fn platforms_env(&self) -> Option<Env> {
+        Env::from_str(self.rustc_env()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Architecture, Environment, OperatingSystem};
+
+    #[test]
+    fn to_platform() {
+        for triple in [
+            "x86_64-unknown-linux-gnu",
+            "aarch64-apple-darwin",
+            "x86_64-pc-windows-msvc",
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            let platform = t.to_platform().unwrap();
+            assert_eq!(platform.target_triple, *triple);
+            assert_eq!(t.platforms_arch(), Some(platform.target_arch));
+            assert_eq!(t.platforms_os(), Some(platform.target_os));
+            assert_eq!(t.platforms_env(), Some(platform.target_env));
+            assert_eq!(Triple::try_from(platform).unwrap(), t);
+        }
+    }
+
+    #[test]
+    fn lossy() {
+        let t = Triple::from_str("thumbv7em-none-eabihf").unwrap();
+        assert_eq!(t.platforms_arch(), Some(Arch::Arm));
+        assert_eq!(t.platforms_os(), Some(OS::None));
+        assert_eq!(t.platforms_env(), Some(Env::None));
+
+        let t = Triple::from_str("armv7-unknown-linux-gnueabihf").unwrap();
+        assert_eq!(t.platforms_env(), Some(Env::Gnu));
+
+        let t = Triple::from_str("x86_64-apple-macosx10.7.0").unwrap();
+        assert!(t.to_platform().is_none());
+        assert_eq!(t.platforms_os(), Some(OS::MacOS));
+
+        let t = Triple::unknown();
+        assert_eq!(t.architecture, Architecture::Unknown);
+        assert_eq!(t.platforms_arch(), None);
+        assert_eq!(t.operating_system, OperatingSystem::Unknown);
+        assert_eq!(t.environment, Environment::Unknown);
+    }
+}
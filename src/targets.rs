@@ -30,7 +30,11 @@ pub enum Architecture {
     Riscv32(Riscv32Architecture),
     Riscv64(Riscv64Architecture),
     S390x,
+    Sbf,
     Sparc,
+    /// SPARC V8, as implemented by the LEON processors used in aerospace.
+    /// Particular LEON cores are selected by CPU rather than by triple.
+    Sparcv8,
     Sparc64,
     Sparcv9,
     Wasm32,
@@ -38,6 +42,27 @@ pub enum Architecture {
     X86_64,
 }
 
+/// A group of related architectures, such as all the 32-bit and 64-bit
+/// variants of x86.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(missing_docs)]
+pub enum ArchitectureFamily {
+    AmdGpu,
+    Arm,
+    Hexagon,
+    Mips,
+    Msp430,
+    Nvptx,
+    PowerPc,
+    RiscV,
+    S390x,
+    Sbf,
+    Sparc,
+    Wasm,
+    X86,
+}
+
 #[non_exhaustive]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[allow(missing_docs)]
@@ -71,6 +96,7 @@ pub enum ArmArchitecture {
     Armv8_5a,
     Armv8mBase,
     Armv8mMain,
+    Armv8_1mMain,
     Armv8r,
 
     Armebv7r,
@@ -83,6 +109,34 @@ pub enum ArmArchitecture {
     Thumbv7neon,
     Thumbv8mBase,
     Thumbv8mMain,
+    Thumbv8_1mMain,
+}
+
+/// An ARM architecture version, such as Armv8.2-A, ordered by major version,
+/// then minor version, then profile.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArmVersion {
+    /// The major version, such as the 8 in Armv8.2-A.
+    pub major: u8,
+    /// The minor version, such as the 2 in Armv8.2-A.
+    pub minor: u8,
+    /// The profile, such as the A in Armv8.2-A.
+    pub profile: ArmProfile,
+}
+
+/// An ARM architecture profile.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ArmProfile {
+    /// Versions before Armv7, which weren't divided into profiles, apart
+    /// from Armv6-M.
+    Classic,
+    /// The application profile, with an MMU.
+    A,
+    /// The real-time profile.
+    R,
+    /// The microcontroller profile.
+    M,
 }
 
 #[non_exhaustive]
@@ -149,6 +203,7 @@ impl ArmArchitecture {
             | Self::Armv8_5a
             | Self::Armv8mBase
             | Self::Armv8mMain
+            | Self::Armv8_1mMain
             | Self::Armv8r
             | Self::Armebv7r => false,
             Self::Thumbeb
@@ -158,7 +213,179 @@ impl ArmArchitecture {
             | Self::Thumbv7m
             | Self::Thumbv7neon
             | Self::Thumbv8mBase
-            | Self::Thumbv8mMain => true,
+            | Self::Thumbv8mMain
+            | Self::Thumbv8_1mMain => true,
+        }
+    }
+
+    /// Test if this architecture is an M-profile (microcontroller) core.
+    pub fn is_mprofile(self) -> bool {
+        match self {
+            Self::Arm
+            | Self::Armeb
+            | Self::Armv4
+            | Self::Armv4t
+            | Self::Armv5t
+            | Self::Armv5te
+            | Self::Armv5tej
+            | Self::Armv6
+            | Self::Armv6j
+            | Self::Armv6k
+            | Self::Armv6z
+            | Self::Armv6kz
+            | Self::Armv6t2
+            | Self::Armv7
+            | Self::Armv7a
+            | Self::Armv7ve
+            | Self::Armv7r
+            | Self::Armv7s
+            | Self::Armv8
+            | Self::Armv8a
+            | Self::Armv8_1a
+            | Self::Armv8_2a
+            | Self::Armv8_3a
+            | Self::Armv8_4a
+            | Self::Armv8_5a
+            | Self::Armv8r
+            | Self::Armebv7r
+            | Self::Thumbeb
+            | Self::Thumbv7a
+            | Self::Thumbv7neon => false,
+            Self::Armv6m
+            | Self::Armv7m
+            | Self::Armv8mBase
+            | Self::Armv8mMain
+            | Self::Armv8_1mMain
+            | Self::Thumbv6m
+            | Self::Thumbv7em
+            | Self::Thumbv7m
+            | Self::Thumbv8mBase
+            | Self::Thumbv8mMain
+            | Self::Thumbv8_1mMain => true,
+        }
+    }
+
+    /// Test if this architecture supports the Armv8-M security extension
+    /// (CMSE), which provides TrustZone for M-profile cores.
+    pub fn has_cmse(self) -> bool {
+        matches!(
+            self,
+            Self::Armv8mBase
+                | Self::Armv8mMain
+                | Self::Armv8_1mMain
+                | Self::Thumbv8mBase
+                | Self::Thumbv8mMain
+                | Self::Thumbv8_1mMain
+        )
+    }
+
+    /// Return the architecture version this implements, or `None` for the
+    /// generic `arm`, `armeb`, and `thumbeb`, which don't name one.
+    pub fn version(self) -> Option<ArmVersion> {
+        let (major, minor, profile) = match self {
+            Self::Arm | Self::Armeb | Self::Thumbeb => return None,
+            Self::Armv4 | Self::Armv4t => (4, 0, ArmProfile::Classic),
+            Self::Armv5t | Self::Armv5te | Self::Armv5tej => (5, 0, ArmProfile::Classic),
+            Self::Armv6
+            | Self::Armv6j
+            | Self::Armv6k
+            | Self::Armv6z
+            | Self::Armv6kz
+            | Self::Armv6t2 => (6, 0, ArmProfile::Classic),
+            Self::Armv6m | Self::Thumbv6m => (6, 0, ArmProfile::M),
+            Self::Armv7
+            | Self::Armv7a
+            | Self::Armv7ve
+            | Self::Armv7s
+            | Self::Thumbv7a
+            | Self::Thumbv7neon => (7, 0, ArmProfile::A),
+            Self::Armv7r | Self::Armebv7r => (7, 0, ArmProfile::R),
+            Self::Armv7m | Self::Thumbv7em | Self::Thumbv7m => (7, 0, ArmProfile::M),
+            Self::Armv8 | Self::Armv8a => (8, 0, ArmProfile::A),
+            Self::Armv8_1a => (8, 1, ArmProfile::A),
+            Self::Armv8_2a => (8, 2, ArmProfile::A),
+            Self::Armv8_3a => (8, 3, ArmProfile::A),
+            Self::Armv8_4a => (8, 4, ArmProfile::A),
+            Self::Armv8_5a => (8, 5, ArmProfile::A),
+            Self::Armv8r => (8, 0, ArmProfile::R),
+            Self::Armv8mBase | Self::Armv8mMain | Self::Thumbv8mBase | Self::Thumbv8mMain => {
+                (8, 0, ArmProfile::M)
+            }
+            Self::Armv8_1mMain | Self::Thumbv8_1mMain => (8, 1, ArmProfile::M),
+        };
+        Some(ArmVersion {
+            major,
+            minor,
+            profile,
+        })
+    }
+
+    /// Test if this architecture includes NEON (Advanced SIMD) by default.
+    ///
+    /// Generic Armv7-A targets leave NEON out, since some cores lack it, so
+    /// only `thumbv7neon`, Apple's `armv7s`, and Armv8-A, where it's
+    /// effectively universal, count.
+    pub fn has_neon(self) -> bool {
+        matches!(
+            self,
+            Self::Thumbv7neon
+                | Self::Armv7s
+                | Self::Armv8
+                | Self::Armv8a
+                | Self::Armv8_1a
+                | Self::Armv8_2a
+                | Self::Armv8_3a
+                | Self::Armv8_4a
+                | Self::Armv8_5a
+        )
+    }
+
+    /// Test if this architecture can switch between the ARM and Thumb
+    /// instruction sets, as with `BX`. This arrived with Armv4T; plain Armv4
+    /// has no Thumb, and M-profile cores have only Thumb.
+    pub fn supports_interworking(self) -> bool {
+        match self {
+            Self::Armv4
+            | Self::Armv6m
+            | Self::Armv7m
+            | Self::Armv8mBase
+            | Self::Armv8mMain
+            | Self::Armv8_1mMain
+            | Self::Thumbv6m
+            | Self::Thumbv7em
+            | Self::Thumbv7m
+            | Self::Thumbv8mBase
+            | Self::Thumbv8mMain
+            | Self::Thumbv8_1mMain => false,
+            Self::Arm
+            | Self::Armeb
+            | Self::Armv4t
+            | Self::Armv5t
+            | Self::Armv5te
+            | Self::Armv5tej
+            | Self::Armv6
+            | Self::Armv6j
+            | Self::Armv6k
+            | Self::Armv6z
+            | Self::Armv6kz
+            | Self::Armv6t2
+            | Self::Armv7
+            | Self::Armv7a
+            | Self::Armv7ve
+            | Self::Armv7r
+            | Self::Armv7s
+            | Self::Armv8
+            | Self::Armv8a
+            | Self::Armv8_1a
+            | Self::Armv8_2a
+            | Self::Armv8_3a
+            | Self::Armv8_4a
+            | Self::Armv8_5a
+            | Self::Armv8r
+            | Self::Armebv7r
+            | Self::Thumbeb
+            | Self::Thumbv7a
+            | Self::Thumbv7neon => true,
         }
     }
 
@@ -167,7 +394,7 @@ impl ArmArchitecture {
     // }
 
     /// Return the pointer bit width of this target's architecture.
-    pub fn pointer_width(self) -> PointerWidth {
+    pub const fn pointer_width(self) -> PointerWidth {
         match self {
             Self::Arm
             | Self::Armeb
@@ -198,6 +425,7 @@ impl ArmArchitecture {
             | Self::Armv8_5a
             | Self::Armv8mBase
             | Self::Armv8mMain
+            | Self::Armv8_1mMain
             | Self::Armv8r
             | Self::Armebv7r
             | Self::Thumbeb
@@ -207,7 +435,8 @@ impl ArmArchitecture {
             | Self::Thumbv7m
             | Self::Thumbv7neon
             | Self::Thumbv8mBase
-            | Self::Thumbv8mMain => PointerWidth::U32,
+            | Self::Thumbv8mMain
+            | Self::Thumbv8_1mMain => PointerWidth::U32,
         }
     }
 
@@ -242,6 +471,7 @@ impl ArmArchitecture {
             | Self::Armv8_5a
             | Self::Armv8mBase
             | Self::Armv8mMain
+            | Self::Armv8_1mMain
             | Self::Armv8r
             | Self::Thumbv6m
             | Self::Thumbv7a
@@ -249,7 +479,8 @@ impl ArmArchitecture {
             | Self::Thumbv7m
             | Self::Thumbv7neon
             | Self::Thumbv8mBase
-            | Self::Thumbv8mMain => Endianness::Little,
+            | Self::Thumbv8mMain
+            | Self::Thumbv8_1mMain => Endianness::Little,
             Self::Armeb | Self::Armebv7r | Self::Thumbeb => Endianness::Big,
         }
     }
@@ -267,8 +498,16 @@ impl Aarch64Architecture {
 
     // }
 
+    /// Test if this architecture includes NEON (Advanced SIMD), which AArch64
+    /// always does.
+    pub fn has_neon(self) -> bool {
+        match self {
+            Self::Aarch64 | Self::Aarch64be => true,
+        }
+    }
+
     /// Return the pointer bit width of this target's architecture.
-    pub fn pointer_width(self) -> PointerWidth {
+    pub const fn pointer_width(self) -> PointerWidth {
         match self {
             Self::Aarch64 | Self::Aarch64be => PointerWidth::U64,
         }
@@ -289,6 +528,11 @@ impl Aarch64Architecture {
 #[allow(missing_docs)]
 pub enum Riscv32Architecture {
     Riscv32,
+    /// The RV32E embedded base, which has only 16 general-purpose registers
+    /// rather than 32, as do `Riscv32em` and `Riscv32emc`.
+    Riscv32e,
+    Riscv32em,
+    Riscv32emc,
     Riscv32i,
     Riscv32imac,
     Riscv32imc,
@@ -341,6 +585,7 @@ pub enum Mips64Architecture {
 #[derive(Clone, Debug, Eq)]
 pub enum CustomVendor {
     /// An owned `String`. This supports the general case.
+    #[allow(clippy::box_collection)]
     Owned(Box<String>),
     /// A static `str`, so that `CustomVendor` can be constructed in `const`
     /// contexts.
@@ -348,6 +593,17 @@ pub enum CustomVendor {
 }
 
 impl CustomVendor {
+    /// Construct a custom vendor from `name`, checking it against the rules
+    /// `Vendor::from_str` applies. This fails if `name` isn't acceptable as a
+    /// custom vendor, including if it names one of the known vendors.
+    #[allow(clippy::result_unit_err)]
+    pub fn try_new(name: &str) -> Result<Self, ()> {
+        match Vendor::from_str(name)? {
+            Vendor::Custom(custom) => Ok(custom),
+            _ => Err(()),
+        }
+    }
+
     /// Extracts a string slice.
     pub fn as_str(&self) -> &str {
         match self {
@@ -355,6 +611,15 @@ impl CustomVendor {
             Self::Static(s) => s,
         }
     }
+
+    /// Test whether this name follows the rules `Vendor::from_str` applies to
+    /// custom vendors, so that a triple containing it can be parsed back.
+    ///
+    /// Custom vendors produced by parsing are always valid, but ones which
+    /// are constructed directly aren't checked.
+    pub fn is_valid(&self) -> bool {
+        is_valid_custom_vendor(self.as_str())
+    }
 }
 
 impl PartialEq for CustomVendor {
@@ -378,11 +643,14 @@ pub enum Vendor {
     Unknown,
     Amd,
     Apple,
+    Buildroot,
     Experimental,
     Fortanix,
     Nvidia,
+    Openwrt,
     Pc,
     Rumprun,
+    Solana,
     Sun,
     Uwp,
     Wrs,
@@ -408,10 +676,12 @@ pub enum OperatingSystem {
     Bitrig,
     Cloudabi,
     Cuda,
+    /// macOS, without a version, as in `x86_64-apple-darwin`. This is how
+    /// rustc spells macOS targets.
     Darwin,
     Dragonfly,
     Emscripten,
-    Freebsd,
+    Freebsd(Option<OsVersion>),
     Fuchsia,
     Haiku,
     Hermit,
@@ -419,19 +689,38 @@ pub enum OperatingSystem {
     Ios,
     L4re,
     Linux,
-    MacOSX { major: u16, minor: u16, patch: u16 },
+    /// macOS with a minimum version, as in `x86_64-apple-macosx10.7.0`. This
+    /// is the same platform as `Darwin`, spelled as LLVM and Clang do.
+    MacOSX {
+        major: u16,
+        minor: u16,
+        patch: u16,
+    },
     Nebulet,
-    Netbsd,
+    Netbsd(Option<OsVersion>),
     None_,
-    Openbsd,
+    Openbsd(Option<OsVersion>),
     OpTee,
     Psp,
     Redox,
+    Solana,
     Solaris,
     Uefi,
     VxWorks,
     Wasi,
     Windows,
+    /// An operating system taught to the parser with `register_custom_os`.
+    Custom(&'static str),
+}
+
+/// A version number attached to an operating system name, such as the `7.4`
+/// in `openbsd7.4` or the `12` in `freebsd12`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub struct OsVersion {
+    pub major: u16,
+    /// The minor version, or `None` if only a major version was given.
+    pub minor: Option<u16>,
 }
 
 /// The "environment" field, which specifies an ABI environment on top of the
@@ -451,20 +740,73 @@ pub enum Environment {
     Gnuabi64,
     Gnueabi,
     Gnueabihf,
+    Gnullvm,
     Gnuspe,
     Gnux32,
+    /// The AArch64 ILP32 ABI with glibc, spelled `gnu_ilp32`, which has
+    /// 32-bit pointers like `Gnux32` does on x86_64.
+    GnuIlp32,
+    /// Glibc with the soft-float ABI, as LLVM spells it for MIPS and
+    /// LoongArch.
+    Gnusf,
     Macabi,
+    /// An Apple simulator, as in `aarch64-apple-ios-sim`.
+    Sim,
     Musl,
     Musleabi,
     Musleabihf,
     Muslabi64,
+    /// Musl with the soft-float ABI, the counterpart of `Gnusf`.
+    Muslsf,
     Msvc,
     Kernel,
+    /// An explicit `none` in the environment position, as distinct from an
+    /// omitted environment.
+    None_,
     Uclibc,
+    Uclibceabi,
+    Uclibceabihf,
+    Relibc,
     Sgx,
     Softfloat,
     Spe,
-    TrustZone
+    TrustZone,
+
+    /// The AMD GPU instruction set to target. By convention this follows an
+    /// empty environment field, as in `amdgcn-amd-amdhsa--gfx900`.
+    AmdGfx(AmdGfxVersion),
+}
+
+/// An AMD GPU instruction-set version, such as the `gfx90a` in
+/// `amdgcn-amd-amdhsa--gfx90a`.
+///
+/// The digits after `gfx` are stored as a hexadecimal number, so `gfx900` is
+/// `AmdGfxVersion(0x900)` and `gfx1030` is `AmdGfxVersion(0x1030)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AmdGfxVersion(pub u16);
+
+impl fmt::Display for AmdGfxVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "gfx{:x}", self.0)
+    }
+}
+
+impl FromStr for AmdGfxVersion {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        let digits = s.strip_prefix("gfx").ok_or(())?;
+        // Insist on the canonical spelling so that `Display` round-trips.
+        if !(3..=4).contains(&digits.len())
+            || !digits.starts_with(|c: char| ('1'..='9').contains(&c))
+            || !digits
+                .chars()
+                .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+        {
+            return Err(());
+        }
+        u16::from_str_radix(digits, 16).map(Self).map_err(|_| ())
+    }
 }
 
 /// The "binary format" field, which is usually omitted, and the binary format
@@ -477,11 +819,17 @@ pub enum BinaryFormat {
     Elf,
     Coff,
     Macho,
+    /// A core WebAssembly module.
     Wasm,
+    /// A WebAssembly component, as defined by the component model. This is a
+    /// different container from a core module, and is never implied; wasm
+    /// triples default to `Wasm`.
+    WasmComponent,
 }
 
 impl Architecture {
     /// Return the endianness of this architecture.
+    #[allow(clippy::result_unit_err)]
     pub fn endianness(self) -> Result<Endianness, ()> {
         match self {
             Self::Unknown => Err(()),
@@ -500,6 +848,7 @@ impl Architecture {
             | Self::Powerpc64le
             | Self::Riscv32(_)
             | Self::Riscv64(_)
+            | Self::Sbf
             | Self::Wasm32
             | Self::Wasm64
             | Self::X86_64 => Ok(Endianness::Little),
@@ -511,26 +860,68 @@ impl Architecture {
             | Self::Powerpc64
             | Self::S390x
             | Self::Sparc
+            | Self::Sparcv8
             | Self::Sparc64
             | Self::Sparcv9 => Ok(Endianness::Big),
         }
     }
 
+    /// Return the family this architecture belongs to, or `None` if it's
+    /// unknown. 32-bit and 64-bit variants share a family, so `Aarch64` is in
+    /// the `Arm` family.
+    pub fn family(self) -> Option<ArchitectureFamily> {
+        Some(match self {
+            Self::Unknown => return None,
+            Self::AmdGcn => ArchitectureFamily::AmdGpu,
+            Self::Arm(_) | Self::Aarch64(_) => ArchitectureFamily::Arm,
+            Self::Asmjs | Self::Wasm32 | Self::Wasm64 => ArchitectureFamily::Wasm,
+            Self::Hexagon => ArchitectureFamily::Hexagon,
+            Self::X86_32(_) | Self::X86_64 => ArchitectureFamily::X86,
+            Self::Mips32(_) | Self::Mips64(_) => ArchitectureFamily::Mips,
+            Self::Msp430 => ArchitectureFamily::Msp430,
+            Self::Nvptx64 => ArchitectureFamily::Nvptx,
+            Self::Powerpc | Self::Powerpc64 | Self::Powerpc64le => ArchitectureFamily::PowerPc,
+            Self::Riscv32(_) | Self::Riscv64(_) => ArchitectureFamily::RiscV,
+            Self::S390x => ArchitectureFamily::S390x,
+            Self::Sbf => ArchitectureFamily::Sbf,
+            Self::Sparc | Self::Sparcv8 | Self::Sparc64 | Self::Sparcv9 => {
+                ArchitectureFamily::Sparc
+            }
+        })
+    }
+
     /// Return the pointer bit width of this target's architecture.
+    #[allow(clippy::result_unit_err)]
     pub fn pointer_width(self) -> Result<PointerWidth, ()> {
+        self.pointer_width_const().ok_or(())
+    }
+
+    /// Return the width of this architecture's general-purpose registers.
+    ///
+    /// Unlike [`Triple::pointer_width`], this ignores ILP32 environments such
+    /// as `gnux32`, which keep 64-bit registers but use 32-bit pointers.
+    #[allow(clippy::result_unit_err)]
+    pub fn register_width(self) -> Result<PointerWidth, ()> {
+        self.pointer_width()
+    }
+
+    /// Return the pointer bit width of this target's architecture, in a form
+    /// usable in `const` contexts.
+    pub const fn pointer_width_const(self) -> Option<PointerWidth> {
         match self {
-            Self::Unknown => Err(()),
-            Self::Msp430 => Ok(PointerWidth::U16),
-            Self::Arm(arm) => Ok(arm.pointer_width()),
-            Self::Aarch64(aarch) => Ok(aarch.pointer_width()),
+            Self::Unknown => None,
+            Self::Msp430 => Some(PointerWidth::U16),
+            Self::Arm(arm) => Some(arm.pointer_width()),
+            Self::Aarch64(aarch) => Some(aarch.pointer_width()),
             Self::Asmjs
             | Self::Hexagon
             | Self::X86_32(_)
             | Self::Riscv32(_)
             | Self::Sparc
+            | Self::Sparcv8
             | Self::Wasm32
             | Self::Mips32(_)
-            | Self::Powerpc => Ok(PointerWidth::U32),
+            | Self::Powerpc => Some(PointerWidth::U32),
             Self::AmdGcn
             | Self::Powerpc64le
             | Self::Riscv64(_)
@@ -539,25 +930,257 @@ impl Architecture {
             | Self::Nvptx64
             | Self::Powerpc64
             | Self::S390x
+            | Self::Sbf
             | Self::Sparc64
             | Self::Sparcv9
-            | Self::Wasm64 => Ok(PointerWidth::U64),
+            | Self::Wasm64 => Some(PointerWidth::U64),
         }
     }
 }
 
+impl OperatingSystem {
+    /// Return every operating system this crate knows, for enumerating them
+    /// in documentation or validation.
+    ///
+    /// Operating systems which can carry a version appear without one.
+    /// `MacOSX` always carries a version, so it's left out in favor of
+    /// `Darwin`, and custom operating systems are left out too.
+    pub fn all() -> &'static [Self] {
+        &[
+            Self::Unknown,
+            Self::AmdHsa,
+            Self::Bitrig,
+            Self::Cloudabi,
+            Self::Cuda,
+            Self::Darwin,
+            Self::Dragonfly,
+            Self::Emscripten,
+            Self::Freebsd(None),
+            Self::Fuchsia,
+            Self::Haiku,
+            Self::Hermit,
+            Self::Illumos,
+            Self::Ios,
+            Self::L4re,
+            Self::Linux,
+            Self::Nebulet,
+            Self::Netbsd(None),
+            Self::None_,
+            Self::Openbsd(None),
+            Self::OpTee,
+            Self::Psp,
+            Self::Redox,
+            Self::Solana,
+            Self::Solaris,
+            Self::Uefi,
+            Self::VxWorks,
+            Self::Wasi,
+            Self::Windows,
+        ]
+    }
+
+    /// Test whether this operating system is no longer maintained. These are
+    /// still recognized, so that existing triples keep parsing, but tools may
+    /// want to warn about them.
+    pub fn is_deprecated(self) -> bool {
+        matches!(self, Self::Bitrig | Self::Cloudabi | Self::Nebulet)
+    }
+
+    /// Return the operating system to suggest in place of a deprecated one,
+    /// for migration warnings, or `None` if there's no natural successor.
+    ///
+    /// Bitrig was merged back into OpenBSD, and WASI took up CloudABI's
+    /// capability-based design. Nebulet has no successor.
+    pub fn replacement(self) -> Option<Self> {
+        match self {
+            Self::Bitrig => Some(Self::Openbsd(None)),
+            Self::Cloudabi => Some(Self::Wasi),
+            _ => None,
+        }
+    }
+
+    /// Test whether rustc places this operating system in the `unix` family.
+    /// Note that this includes the Darwin family and Emscripten.
+    pub fn is_like_unix(self) -> bool {
+        matches!(
+            self,
+            Self::Bitrig
+                | Self::Cloudabi
+                | Self::Darwin
+                | Self::Dragonfly
+                | Self::Emscripten
+                | Self::Freebsd(_)
+                | Self::Fuchsia
+                | Self::Haiku
+                | Self::Illumos
+                | Self::Ios
+                | Self::L4re
+                | Self::Linux
+                | Self::MacOSX { .. }
+                | Self::Netbsd(_)
+                | Self::Openbsd(_)
+                | Self::Redox
+                | Self::Solaris
+                | Self::VxWorks
+        )
+    }
+
+    /// Test whether this operating system is built on a microkernel, or is
+    /// the Hermit unikernel. These offer a much narrower system interface
+    /// than a monolithic kernel, so runtime code which assumes POSIX may not
+    /// work even where `is_like_unix` is true.
+    pub fn is_microkernel(self) -> bool {
+        matches!(
+            self,
+            Self::Fuchsia | Self::Hermit | Self::L4re | Self::Redox
+        )
+    }
+
+    /// Test whether this operating system follows Windows conventions, which
+    /// includes UEFI with its PE/COFF images.
+    pub fn is_like_windows(self) -> bool {
+        matches!(self, Self::Uefi | Self::Windows)
+    }
+
+    /// Test whether this is one of Apple's Darwin-based operating systems.
+    pub fn is_like_darwin(self) -> bool {
+        matches!(self, Self::Darwin | Self::Ios | Self::MacOSX { .. })
+    }
+
+    /// An alias for [`OperatingSystem::is_like_darwin`], which is true for
+    /// `Darwin` and `MacOSX`, both macOS with and without a version, and for
+    /// `Ios`.
+    pub fn is_darwin_family(self) -> bool {
+        self.is_like_darwin()
+    }
+
+    /// Test whether rustc places this operating system in the `wasm` family.
+    /// Note that bare wasm targets have an `Unknown` operating system, so
+    /// `Triple::target_family` also looks at the architecture.
+    pub fn is_like_wasm(self) -> bool {
+        matches!(self, Self::Emscripten | Self::Wasi)
+    }
+
+    /// Return the environments which are commonly paired with this operating
+    /// system, for presenting choices or validating configurations.
+    ///
+    /// This is a curated list of the combinations in use, not an exhaustive
+    /// one. It doesn't include `Environment::Unknown`, which stands for an
+    /// omitted environment.
+    pub fn valid_environments(self) -> &'static [Environment] {
+        match self {
+            Self::AmdHsa => &[Environment::AmdGiz],
+            Self::Cloudabi => &[Environment::Eabihf],
+            Self::Hermit => &[Environment::Kernel],
+            Self::Ios => &[Environment::Macabi, Environment::Sim],
+            Self::L4re => &[Environment::Uclibc],
+            Self::Linux => &[
+                Environment::Android,
+                Environment::Androideabi,
+                Environment::Gnu,
+                Environment::Gnuabi64,
+                Environment::Gnueabi,
+                Environment::Gnueabihf,
+                Environment::Gnuspe,
+                Environment::Gnux32,
+                Environment::GnuIlp32,
+                Environment::Gnusf,
+                Environment::Kernel,
+                Environment::Musl,
+                Environment::Musleabi,
+                Environment::Musleabihf,
+                Environment::Muslabi64,
+                Environment::Muslsf,
+                Environment::Uclibc,
+                Environment::Uclibceabi,
+                Environment::Uclibceabihf,
+            ],
+            Self::Netbsd(_) => &[Environment::Eabi, Environment::Eabihf],
+            Self::None_ => &[
+                Environment::Eabi,
+                Environment::Eabihf,
+                Environment::Softfloat,
+            ],
+            Self::OpTee => &[Environment::TrustZone],
+            Self::Redox => &[Environment::Relibc],
+            Self::Unknown => &[Environment::Sgx],
+            Self::VxWorks => &[Environment::Eabihf, Environment::Spe],
+            Self::Windows => &[Environment::Gnu, Environment::Gnullvm, Environment::Msvc],
+            _ => &[],
+        }
+    }
+}
+
+impl Environment {
+    /// Return the pointer width this environment imposes in place of the
+    /// architecture's, for ILP32 ABIs on 64-bit architectures such as x32 and
+    /// AArch64's `gnu_ilp32`.
+    pub fn pointer_width_override(self) -> Option<PointerWidth> {
+        match self {
+            Self::Gnux32 | Self::GnuIlp32 => Some(PointerWidth::U32),
+            _ => None,
+        }
+    }
+
+    /// Test whether this environment names a hard-float ABI, which passes
+    /// floating-point values in floating-point registers.
+    pub fn is_hard_float(self) -> bool {
+        matches!(
+            self,
+            Self::Eabihf | Self::Gnueabihf | Self::Musleabihf | Self::Uclibceabihf
+        )
+    }
+
+    /// Test whether this environment names a soft-float ABI, which passes
+    /// floating-point values in integer registers, whether or not the
+    /// hardware has an FPU.
+    pub fn is_soft_float(self) -> bool {
+        matches!(
+            self,
+            Self::Androideabi
+                | Self::Eabi
+                | Self::Gnueabi
+                | Self::Gnusf
+                | Self::Musleabi
+                | Self::Muslsf
+                | Self::Softfloat
+                | Self::Uclibceabi
+        )
+    }
+}
+
+/// Return the binary format a triple with the given fields has when its
+/// binary format is omitted, as when parsing `x86_64-unknown-linux-gnu`.
+///
+/// The vendor never affects this, so it isn't needed.
+pub fn default_binary_format_for(
+    architecture: Architecture,
+    operating_system: OperatingSystem,
+    environment: Environment,
+) -> BinaryFormat {
+    default_binary_format(&Triple {
+        architecture,
+        operating_system,
+        environment,
+        ..Triple::unknown()
+    })
+}
+
 /// Return the binary format implied by this target triple, ignoring its
 /// `binary_format` field.
 pub(crate) fn default_binary_format(triple: &Triple) -> BinaryFormat {
     match triple.operating_system {
-        OperatingSystem::None_ => match triple.environment {
-            Environment::Eabi | Environment::Eabihf => BinaryFormat::Elf,
+        OperatingSystem::None_ => match (triple.architecture, triple.environment) {
+            (Architecture::Wasm32, _) | (Architecture::Wasm64, _) => BinaryFormat::Wasm,
+            (_, Environment::Eabi) | (_, Environment::Eabihf) => BinaryFormat::Elf,
             _ => BinaryFormat::Unknown,
         },
         OperatingSystem::Darwin | OperatingSystem::Ios | OperatingSystem::MacOSX { .. } => {
             BinaryFormat::Macho
         }
         OperatingSystem::Windows => BinaryFormat::Coff,
+        // Note that asm.js output is JavaScript source rather than an object
+        // file, so `asmjs-unknown-emscripten` has no binary format.
         OperatingSystem::Nebulet
         | OperatingSystem::Emscripten
         | OperatingSystem::VxWorks
@@ -566,6 +1189,8 @@ pub(crate) fn default_binary_format(triple: &Triple) -> BinaryFormat {
             Architecture::Wasm32 | Architecture::Wasm64 => BinaryFormat::Wasm,
             _ => BinaryFormat::Unknown,
         },
+        // Nothing is known about operating systems registered at runtime.
+        OperatingSystem::Custom(_) => BinaryFormat::Unknown,
         _ => BinaryFormat::Elf,
     }
 }
@@ -602,6 +1227,7 @@ impl fmt::Display for ArmArchitecture {
             Self::Armv8_5a => "armv8.5a",
             Self::Armv8mBase => "armv8m.base",
             Self::Armv8mMain => "armv8m.main",
+            Self::Armv8_1mMain => "armv8.1m.main",
             Self::Armv8r => "armv8r",
             Self::Thumbeb => "thumbeb",
             Self::Thumbv6m => "thumbv6m",
@@ -611,6 +1237,7 @@ impl fmt::Display for ArmArchitecture {
             Self::Thumbv7neon => "thumbv7neon",
             Self::Thumbv8mBase => "thumbv8m.base",
             Self::Thumbv8mMain => "thumbv8m.main",
+            Self::Thumbv8_1mMain => "thumbv8.1m.main",
             Self::Armebv7r => "armebv7r",
         };
         f.write_str(s)
@@ -632,6 +1259,9 @@ impl fmt::Display for Riscv32Architecture {
         let s = match *self {
             Self::Riscv32 => "riscv32",
             Self::Riscv32i => "riscv32i",
+            Self::Riscv32e => "riscv32e",
+            Self::Riscv32em => "riscv32em",
+            Self::Riscv32emc => "riscv32emc",
             Self::Riscv32imac => "riscv32imac",
             Self::Riscv32imc => "riscv32imc",
         };
@@ -705,7 +1335,9 @@ impl fmt::Display for Architecture {
             Self::Riscv32(riscv32) => riscv32.fmt(f),
             Self::Riscv64(riscv64) => riscv64.fmt(f),
             Self::S390x => f.write_str("s390x"),
+            Self::Sbf => f.write_str("sbf"),
             Self::Sparc => f.write_str("sparc"),
+            Self::Sparcv8 => f.write_str("sparcv8"),
             Self::Sparc64 => f.write_str("sparc64"),
             Self::Sparcv9 => f.write_str("sparcv9"),
             Self::Wasm32 => f.write_str("wasm32"),
@@ -749,6 +1381,7 @@ impl FromStr for ArmArchitecture {
             "armv8.5a" => Self::Armv8_5a,
             "armv8m.base" => Self::Armv8mBase,
             "armv8m.main" => Self::Armv8mMain,
+            "armv8.1m.main" => Self::Armv8_1mMain,
             "armv8r" => Self::Armv8r,
             "thumbeb" => Self::Thumbeb,
             "thumbv6m" => Self::Thumbv6m,
@@ -758,6 +1391,7 @@ impl FromStr for ArmArchitecture {
             "thumbv7neon" => Self::Thumbv7neon,
             "thumbv8m.base" => Self::Thumbv8mBase,
             "thumbv8m.main" => Self::Thumbv8mMain,
+            "thumbv8.1m.main" => Self::Thumbv8_1mMain,
             "armebv7r" => Self::Armebv7r,
             _ => return Err(()),
         })
@@ -784,6 +1418,9 @@ impl FromStr for Riscv32Architecture {
         Ok(match s {
             "riscv32" => Self::Riscv32,
             "riscv32i" => Self::Riscv32i,
+            "riscv32e" => Self::Riscv32e,
+            "riscv32em" => Self::Riscv32em,
+            "riscv32emc" => Self::Riscv32emc,
             "riscv32imac" => Self::Riscv32imac,
             "riscv32imc" => Self::Riscv32imc,
             _ => return Err(()),
@@ -804,6 +1441,9 @@ impl FromStr for Riscv64Architecture {
     }
 }
 
+/// Names of specific CPU generations collapse to the nearest ISA baseline
+/// this crate distinguishes, so `i786` parses as `I686` and displays as
+/// `i686`.
 impl FromStr for X86_32Architecture {
     type Err = ();
 
@@ -811,7 +1451,7 @@ impl FromStr for X86_32Architecture {
         Ok(match s {
             "i386" => Self::I386,
             "i586" => Self::I586,
-            "i686" => Self::I686,
+            "i686" | "i786" => Self::I686,
             _ => return Err(()),
         })
     }
@@ -824,8 +1464,8 @@ impl FromStr for Mips32Architecture {
         Ok(match s {
             "mips" => Self::Mips,
             "mipsel" => Self::Mipsel,
-            "mipsisa32r6" => Self::Mipsisa32r6,
-            "mipsisa32r6el" => Self::Mipsisa32r6el,
+            "mipsisa32r6" | "mips32r6" => Self::Mipsisa32r6,
+            "mipsisa32r6el" | "mips32r6el" => Self::Mipsisa32r6el,
             _ => return Err(()),
         })
     }
@@ -838,8 +1478,8 @@ impl FromStr for Mips64Architecture {
         Ok(match s {
             "mips64" => Self::Mips64,
             "mips64el" => Self::Mips64el,
-            "mipsisa64r6" => Self::Mipsisa64r6,
-            "mipsisa64r6el" => Self::Mipsisa64r6el,
+            "mipsisa64r6" | "mips64r6" => Self::Mipsisa64r6,
+            "mipsisa64r6el" | "mips64r6el" => Self::Mipsisa64r6el,
             _ => return Err(()),
         })
     }
@@ -858,14 +1498,16 @@ impl FromStr for Architecture {
             "nvptx64" => Self::Nvptx64,
             "powerpc" => Self::Powerpc,
             "powerpc64" => Self::Powerpc64,
-            "powerpc64le" => Self::Powerpc64le,
+            "powerpc64le" | "ppc64le" => Self::Powerpc64le,
             "s390x" => Self::S390x,
+            "sbf" => Self::Sbf,
             "sparc" => Self::Sparc,
+            "sparcv8" => Self::Sparcv8,
             "sparc64" => Self::Sparc64,
             "sparcv9" => Self::Sparcv9,
             "wasm32" => Self::Wasm32,
             "wasm64" => Self::Wasm64,
-            "x86_64" => Self::X86_64,
+            "x86_64" | "amd64" => Self::X86_64,
             _ => {
                 if let Ok(arm) = ArmArchitecture::from_str(s) {
                     Self::Arm(arm)
@@ -895,11 +1537,14 @@ impl fmt::Display for Vendor {
             Self::Unknown => "unknown",
             Self::Amd => "amd",
             Self::Apple => "apple",
+            Self::Buildroot => "buildroot",
             Self::Experimental => "experimental",
             Self::Fortanix => "fortanix",
             Self::Nvidia => "nvidia",
+            Self::Openwrt => "openwrt",
             Self::Pc => "pc",
             Self::Rumprun => "rumprun",
+            Self::Solana => "solana",
             Self::Sun => "sun",
             Self::Uwp => "uwp",
             Self::Wrs => "wrs",
@@ -917,55 +1562,62 @@ impl FromStr for Vendor {
             "unknown" => Self::Unknown,
             "amd" => Self::Amd,
             "apple" => Self::Apple,
+            "buildroot" => Self::Buildroot,
             "experimental" => Self::Experimental,
             "fortanix" => Self::Fortanix,
             "nvidia" => Self::Nvidia,
+            "openwrt" => Self::Openwrt,
             "pc" => Self::Pc,
             "rumprun" => Self::Rumprun,
+            "solana" => Self::Solana,
             "sun" => Self::Sun,
             "uwp" => Self::Uwp,
             "wrs" => Self::Wrs,
             custom => {
                 use alloc::borrow::ToOwned;
 
-                // A custom vendor. Since triple syntax is so loosely defined,
-                // be as conservative as we can to avoid potential ambiguities.
-                // We err on the side of being too strict here, as we can
-                // always relax it if needed.
-
-                // Don't allow empty string names.
-                if custom.is_empty() {
+                if !is_valid_custom_vendor(custom) {
                     return Err(());
                 }
 
-                // Don't allow any other recognized name as a custom vendor,
-                // since vendors can be omitted in some contexts.
-                if Architecture::from_str(custom).is_ok()
-                    || OperatingSystem::from_str(custom).is_ok()
-                    || Environment::from_str(custom).is_ok()
-                    || BinaryFormat::from_str(custom).is_ok()
-                {
-                    return Err(());
-                }
+                Self::Custom(CustomVendor::Owned(Box::new(custom.to_owned())))
+            }
+        })
+    }
+}
 
-                // Require the first character to be an ascii lowercase.
-                if !custom.chars().next().unwrap().is_ascii_lowercase() {
-                    return Err(());
-                }
+/// Test whether `custom` is acceptable as the name of a custom vendor.
+fn is_valid_custom_vendor(custom: &str) -> bool {
+    // Since triple syntax is so loosely defined, be as conservative as we can
+    // to avoid potential ambiguities. We err on the side of being too strict
+    // here, as we can always relax it if needed.
 
-                // Restrict the set of characters permitted in a custom vendor.
-                let has_restricted = custom.chars().any(|c: char| {
-                    !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '.')
-                });
+    // Don't allow empty string names.
+    if custom.is_empty() {
+        return false;
+    }
 
-                if has_restricted {
-                    return Err(());
-                }
+    // Don't allow any other recognized name as a custom vendor, since
+    // vendors can be omitted in some contexts. Operating systems registered
+    // with `register_custom_os` are still allowed, so that registering one
+    // doesn't change how triples with it as a vendor parse.
+    if Architecture::from_str(custom).is_ok()
+        || OperatingSystem::from_builtin_str(custom).is_ok()
+        || Environment::from_str(custom).is_ok()
+        || BinaryFormat::from_str(custom).is_ok()
+    {
+        return false;
+    }
 
-                Self::Custom(CustomVendor::Owned(Box::new(custom.to_owned())))
-            }
-        })
+    // Require the first character to be an ascii lowercase.
+    if !custom.chars().next().unwrap().is_ascii_lowercase() {
+        return false;
     }
+
+    // Restrict the set of characters permitted in a custom vendor.
+    custom
+        .chars()
+        .all(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '.')
 }
 
 impl fmt::Display for OperatingSystem {
@@ -979,7 +1631,7 @@ impl fmt::Display for OperatingSystem {
             Self::Darwin => "darwin",
             Self::Dragonfly => "dragonfly",
             Self::Emscripten => "emscripten",
-            Self::Freebsd => "freebsd",
+            Self::Freebsd(version) => return write_versioned(f, "freebsd", version),
             Self::Fuchsia => "fuchsia",
             Self::Haiku => "haiku",
             Self::Hermit => "hermit",
@@ -995,50 +1647,158 @@ impl fmt::Display for OperatingSystem {
                 return write!(f, "macosx{}.{}.{}", major, minor, patch);
             }
             Self::Nebulet => "nebulet",
-            Self::Netbsd => "netbsd",
+            Self::Netbsd(version) => return write_versioned(f, "netbsd", version),
             Self::None_ => "none",
-            Self::Openbsd => "openbsd",
+            Self::Openbsd(version) => return write_versioned(f, "openbsd", version),
             Self::OpTee => "optee",
-	    Self::Psp => "psp",
+            Self::Psp => "psp",
             Self::Redox => "redox",
+            Self::Solana => "solana",
             Self::Solaris => "solaris",
             Self::Uefi => "uefi",
             Self::VxWorks => "vxworks",
             Self::Wasi => "wasi",
             Self::Windows => "windows",
+            Self::Custom(name) => name,
         };
         f.write_str(s)
     }
 }
 
+/// Operating system names registered with `register_custom_os`.
+#[cfg(feature = "std")]
+static CUSTOM_OPERATING_SYSTEMS: std::sync::RwLock<Vec<&'static str>> =
+    std::sync::RwLock::new(Vec::new());
+
+/// Teach the parser to recognize `name` as an operating system, so that
+/// triples naming it parse to `OperatingSystem::Custom` and display back in
+/// the same form. This is for the long tail of operating systems which this
+/// crate doesn't know about.
+///
+/// `name` must follow the same rules as a custom vendor: it must start with
+/// an ASCII lowercase letter, contain only ASCII lowercase letters, digits,
+/// `_`, and `.`, and not already name an architecture, vendor, operating
+/// system, environment, or binary format. Registering a name again has no
+/// effect. Names can't be unregistered.
+///
+/// A registered name is only recognized where the operating system field
+/// is expected, so triples using it must spell out the vendor, as in
+/// `x86_64-unknown-managarm`. In the vendor position it's still a custom
+/// vendor, so `x86_64-managarm-linux` parses the same way before and after
+/// `managarm` is registered, and `CustomVendor::is_valid` isn't affected.
+///
+/// Registration is global and thread-safe, and affects every thread which
+/// parses triples. Triples which were parsed before a name was registered
+/// aren't affected, so register names before parsing any triples which use
+/// them.
+#[cfg(feature = "std")]
+#[allow(clippy::result_unit_err)]
+pub fn register_custom_os(name: &'static str) -> Result<(), ()> {
+    if CustomVendor::try_new(name).is_err() {
+        return Err(());
+    }
+    let mut registry = CUSTOM_OPERATING_SYSTEMS.write().unwrap();
+    if !registry.contains(&name) {
+        registry.push(name);
+    }
+    Ok(())
+}
+
+/// Return the registered custom operating system named `name`, if any.
+#[cfg(feature = "std")]
+fn lookup_custom_os(name: &str) -> Option<&'static str> {
+    let registry = CUSTOM_OPERATING_SYSTEMS.read().unwrap();
+    registry.iter().find(|custom| **custom == name).cloned()
+}
+
+/// Write an operating system name followed by its version, if any.
+fn write_versioned(f: &mut fmt::Formatter, name: &str, version: Option<OsVersion>) -> fmt::Result {
+    f.write_str(name)?;
+    if let Some(OsVersion { major, minor }) = version {
+        write!(f, "{}", major)?;
+        if let Some(minor) = minor {
+            write!(f, ".{}", minor)?;
+        }
+    }
+    Ok(())
+}
+
+/// Parse the optional `major` or `major.minor` version following an
+/// operating system name.
+fn parse_os_version(s: &str) -> Result<Option<OsVersion>, ()> {
+    if s.is_empty() {
+        return Ok(None);
+    }
+    if s.contains('.') {
+        let mut version = [0; 2];
+        parse_version(s, &mut version)?;
+        let [major, minor] = version;
+        Ok(Some(OsVersion {
+            major,
+            minor: Some(minor),
+        }))
+    } else {
+        let mut version = [0; 1];
+        parse_version(s, &mut version)?;
+        let [major] = version;
+        Ok(Some(OsVersion { major, minor: None }))
+    }
+}
+
+/// Parse a dot-separated version number with exactly as many components as
+/// `parts` has elements.
+///
+/// Components must be plain decimal numbers without leading zeros, so that
+/// a parsed version displays back the way it was written.
+fn parse_version(s: &str, parts: &mut [u16]) -> Result<(), ()> {
+    let mut nums = s.split('.').map(|num| {
+        let canonical = !num.is_empty()
+            && num.bytes().all(|b| b.is_ascii_digit())
+            && (num == "0" || !num.starts_with('0'));
+        if canonical {
+            num.parse::<u16>().ok()
+        } else {
+            None
+        }
+    });
+    for part in parts.iter_mut() {
+        if let Some(Some(num)) = nums.next() {
+            *part = num;
+        } else {
+            return Err(());
+        }
+    }
+    if nums.next().is_some() {
+        return Err(());
+    }
+    Ok(())
+}
+
 impl FromStr for OperatingSystem {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, ()> {
-        // TODO also parse version number for darwin and ios OSes
-        if s.starts_with("macosx") {
-            // Parse operating system names like `macosx10.7.0`.
-            let s = &s["macosx".len()..];
-            let mut parts = s.split('.').map(|num| num.parse::<u16>());
-
-            macro_rules! get_part {
-                () => {
-                    if let Some(Ok(part)) = parts.next() {
-                        part
-                    } else {
-                        return Err(());
-                    }
-                };
-            }
-
-            let major = get_part!();
-            let minor = get_part!();
-            let patch = get_part!();
-
-            if parts.next().is_some() {
-                return Err(());
+        let builtin = Self::from_builtin_str(s);
+        #[cfg(feature = "std")]
+        if builtin.is_err() {
+            if let Some(custom) = lookup_custom_os(s) {
+                return Ok(Self::Custom(custom));
             }
+        }
+        builtin
+    }
+}
 
+impl OperatingSystem {
+    /// Parse the name of an operating system this crate knows about, ignoring
+    /// names registered with `register_custom_os`.
+    fn from_builtin_str(s: &str) -> Result<Self, ()> {
+        // TODO also parse version number for darwin and ios OSes
+        if let Some(s) = s.strip_prefix("macosx") {
+            // Parse operating system names like `macosx10.7.0`.
+            let mut version = [0; 3];
+            parse_version(s, &mut version)?;
+            let [major, minor, patch] = version;
             return Ok(Self::MacOSX {
                 major,
                 minor,
@@ -1046,6 +1806,17 @@ impl FromStr for OperatingSystem {
             });
         }
 
+        // Parse BSD names, which may carry a version, like `openbsd7.4`.
+        if let Some(s) = s.strip_prefix("freebsd") {
+            return Ok(Self::Freebsd(parse_os_version(s)?));
+        }
+        if let Some(s) = s.strip_prefix("netbsd") {
+            return Ok(Self::Netbsd(parse_os_version(s)?));
+        }
+        if let Some(s) = s.strip_prefix("openbsd") {
+            return Ok(Self::Openbsd(parse_os_version(s)?));
+        }
+
         Ok(match s {
             "unknown" => Self::Unknown,
             "amdhsa" => Self::AmdHsa,
@@ -1055,7 +1826,6 @@ impl FromStr for OperatingSystem {
             "darwin" => Self::Darwin,
             "dragonfly" => Self::Dragonfly,
             "emscripten" => Self::Emscripten,
-            "freebsd" => Self::Freebsd,
             "fuchsia" => Self::Fuchsia,
             "haiku" => Self::Haiku,
             "hermit" => Self::Hermit,
@@ -1064,12 +1834,11 @@ impl FromStr for OperatingSystem {
             "l4re" => Self::L4re,
             "linux" => Self::Linux,
             "nebulet" => Self::Nebulet,
-            "netbsd" => Self::Netbsd,
             "none" => Self::None_,
-            "openbsd" => Self::Openbsd,
-	    "optee" => Self::OpTee,
+            "optee" => Self::OpTee,
             "psp" => Self::Psp,
             "redox" => Self::Redox,
+            "solana" => Self::Solana,
             "solaris" => Self::Solaris,
             "uefi" => Self::Uefi,
             "vxworks" => Self::VxWorks,
@@ -1093,20 +1862,30 @@ impl fmt::Display for Environment {
             Self::Gnuabi64 => "gnuabi64",
             Self::Gnueabi => "gnueabi",
             Self::Gnueabihf => "gnueabihf",
+            Self::Gnullvm => "gnullvm",
             Self::Gnuspe => "gnuspe",
             Self::Gnux32 => "gnux32",
+            Self::GnuIlp32 => "gnu_ilp32",
+            Self::Gnusf => "gnusf",
             Self::Macabi => "macabi",
+            Self::Sim => "sim",
             Self::Musl => "musl",
             Self::Musleabi => "musleabi",
             Self::Musleabihf => "musleabihf",
             Self::Muslabi64 => "muslabi64",
+            Self::Muslsf => "muslsf",
             Self::Msvc => "msvc",
             Self::Kernel => "kernel",
+            Self::None_ => "none",
             Self::Uclibc => "uclibc",
+            Self::Uclibceabi => "uclibceabi",
+            Self::Uclibceabihf => "uclibceabihf",
+            Self::Relibc => "relibc",
             Self::Sgx => "sgx",
             Self::Softfloat => "softfloat",
             Self::Spe => "spe",
-	    Self::TrustZone => "trustzone"
+            Self::TrustZone => "trustzone",
+            Self::AmdGfx(gfx) => return gfx.fmt(f),
         };
         f.write_str(s)
     }
@@ -1127,21 +1906,36 @@ impl FromStr for Environment {
             "gnuabi64" => Self::Gnuabi64,
             "gnueabi" => Self::Gnueabi,
             "gnueabihf" => Self::Gnueabihf,
+            "gnullvm" => Self::Gnullvm,
             "gnuspe" => Self::Gnuspe,
             "gnux32" => Self::Gnux32,
+            "gnu_ilp32" => Self::GnuIlp32,
+            "gnusf" => Self::Gnusf,
             "macabi" => Self::Macabi,
+            "sim" => Self::Sim,
             "musl" => Self::Musl,
             "musleabi" => Self::Musleabi,
             "musleabihf" => Self::Musleabihf,
             "muslabi64" => Self::Muslabi64,
+            "muslsf" => Self::Muslsf,
             "msvc" => Self::Msvc,
             "kernel" => Self::Kernel,
+            "none" => Self::None_,
             "uclibc" => Self::Uclibc,
+            "uclibceabi" => Self::Uclibceabi,
+            "uclibceabihf" => Self::Uclibceabihf,
+            "relibc" => Self::Relibc,
             "sgx" => Self::Sgx,
             "softfloat" => Self::Softfloat,
             "spe" => Self::Spe,
-	    "trustzone" => Self::TrustZone,
-            _ => return Err(()),
+            "trustzone" => Self::TrustZone,
+            _ => {
+                if let Ok(gfx) = AmdGfxVersion::from_str(s) {
+                    Self::AmdGfx(gfx)
+                } else {
+                    return Err(());
+                }
+            }
         })
     }
 }
@@ -1154,6 +1948,7 @@ impl fmt::Display for BinaryFormat {
             Self::Coff => "coff",
             Self::Macho => "macho",
             Self::Wasm => "wasm",
+            Self::WasmComponent => "wasmcomponent",
         };
         f.write_str(s)
     }
@@ -1169,188 +1964,302 @@ impl FromStr for BinaryFormat {
             "coff" => Self::Coff,
             "macho" => Self::Macho,
             "wasm" => Self::Wasm,
+            "wasmcomponent" => Self::WasmComponent,
             _ => return Err(()),
         })
     }
 }
 
+/// Triples which are known to parse and to display back in the same form,
+/// sorted so that they can be binary searched.
+///
+/// This list is constructed from:
+///  - targets emitted by "rustup target list"
+///  - targets emitted by "rustc +nightly --print target-list"
+///
+/// It's public through `Triple::known_triples`, so only real targets belong
+/// here; other spellings the tests round-trip go in `ROUNDTRIP_FIXTURES`.
+pub(crate) const KNOWN_TRIPLES: &[&str] = &[
+    "aarch64-apple-ios",
+    "aarch64-apple-ios-sim",
+    "aarch64-fuchsia",
+    "aarch64-linux-android",
+    "aarch64-pc-windows-msvc",
+    "aarch64-unknown-cloudabi",
+    "aarch64-unknown-freebsd",
+    "aarch64-unknown-hermit",
+    "aarch64-unknown-linux-gnu",
+    "aarch64-unknown-linux-gnu_ilp32",
+    "aarch64-unknown-linux-musl",
+    "aarch64-unknown-netbsd",
+    "aarch64-unknown-none",
+    "aarch64-unknown-none-softfloat",
+    "aarch64-unknown-openbsd",
+    "aarch64-unknown-optee-trustzone",
+    "aarch64-unknown-redox",
+    "aarch64-uwp-windows-msvc",
+    "aarch64-wrs-vxworks",
+    "amdgcn-amd-amdhsa",
+    "amdgcn-amd-amdhsa-amdgiz",
+    "arm-linux-androideabi",
+    "arm-unknown-linux-gnueabi",
+    "arm-unknown-linux-gnueabihf",
+    "arm-unknown-linux-musleabi",
+    "arm-unknown-linux-musleabihf",
+    "armebv7r-none-eabi",
+    "armebv7r-none-eabihf",
+    "armv4t-unknown-linux-gnueabi",
+    "armv5te-unknown-linux-gnueabi",
+    "armv5te-unknown-linux-musleabi",
+    "armv6-unknown-freebsd",
+    "armv6-unknown-netbsd-eabihf",
+    "armv7-apple-ios",
+    "armv7-linux-androideabi",
+    "armv7-unknown-cloudabi-eabihf",
+    "armv7-unknown-freebsd",
+    "armv7-unknown-linux-gnueabi",
+    "armv7-unknown-linux-gnueabihf",
+    "armv7-unknown-linux-musleabi",
+    "armv7-unknown-linux-musleabihf",
+    "armv7-unknown-linux-uclibceabi",
+    "armv7-unknown-linux-uclibceabihf",
+    "armv7-unknown-netbsd-eabihf",
+    "armv7-wrs-vxworks-eabihf",
+    "armv7a-none-eabi",
+    "armv7a-none-eabihf",
+    "armv7r-none-eabi",
+    "armv7r-none-eabihf",
+    "armv7s-apple-ios",
+    "asmjs-unknown-emscripten",
+    "hexagon-unknown-linux-musl",
+    "i386-apple-ios",
+    "i586-pc-windows-msvc",
+    "i586-unknown-linux-gnu",
+    "i586-unknown-linux-musl",
+    "i686-apple-darwin",
+    "i686-apple-macosx10.7.0",
+    "i686-linux-android",
+    "i686-pc-windows-gnu",
+    "i686-pc-windows-msvc",
+    "i686-unknown-cloudabi",
+    "i686-unknown-dragonfly",
+    "i686-unknown-freebsd",
+    "i686-unknown-haiku",
+    "i686-unknown-linux-gnu",
+    "i686-unknown-linux-musl",
+    "i686-unknown-netbsd",
+    "i686-unknown-openbsd",
+    "i686-unknown-uefi",
+    "i686-uwp-windows-gnu",
+    "i686-uwp-windows-msvc",
+    "i686-wrs-vxworks",
+    "mips-unknown-linux-gnu",
+    "mips-unknown-linux-musl",
+    "mips-unknown-linux-uclibc",
+    "mips64-unknown-linux-gnuabi64",
+    "mips64-unknown-linux-muslabi64",
+    "mips64el-unknown-linux-gnuabi64",
+    "mips64el-unknown-linux-muslabi64",
+    "mipsel-sony-psp",
+    "mipsel-unknown-linux-gnu",
+    "mipsel-unknown-linux-musl",
+    "mipsel-unknown-linux-uclibc",
+    "mipsisa32r6-unknown-linux-gnu",
+    "mipsisa32r6el-unknown-linux-gnu",
+    "mipsisa64r6-unknown-linux-gnuabi64",
+    "mipsisa64r6el-unknown-linux-gnuabi64",
+    "msp430-none-elf",
+    "nvptx64-nvidia-cuda",
+    "powerpc-unknown-linux-gnu",
+    "powerpc-unknown-linux-gnuspe",
+    "powerpc-unknown-linux-musl",
+    "powerpc-unknown-netbsd",
+    "powerpc-wrs-vxworks",
+    "powerpc-wrs-vxworks-spe",
+    "powerpc64-unknown-freebsd",
+    "powerpc64-unknown-linux-gnu",
+    "powerpc64-unknown-linux-musl",
+    "powerpc64-wrs-vxworks",
+    "powerpc64le-unknown-linux-gnu",
+    "powerpc64le-unknown-linux-musl",
+    "riscv32e-unknown-none-elf",
+    "riscv32em-unknown-none-elf",
+    "riscv32emc-unknown-none-elf",
+    "riscv32i-unknown-none-elf",
+    "riscv32imac-unknown-none-elf",
+    "riscv32imc-unknown-none-elf",
+    "riscv64gc-unknown-linux-gnu",
+    "riscv64gc-unknown-none-elf",
+    "riscv64imac-unknown-none-elf",
+    "s390x-unknown-linux-gnu",
+    "sbf-solana-solana",
+    "sparc-unknown-linux-gnu",
+    "sparc64-unknown-linux-gnu",
+    "sparc64-unknown-netbsd",
+    "sparc64-unknown-openbsd",
+    "sparcv9-sun-solaris",
+    "thumbv6m-none-eabi",
+    "thumbv7a-pc-windows-msvc",
+    "thumbv7a-uwp-windows-msvc",
+    "thumbv7em-none-eabi",
+    "thumbv7em-none-eabihf",
+    "thumbv7m-none-eabi",
+    "thumbv7neon-linux-androideabi",
+    "thumbv7neon-unknown-linux-gnueabihf",
+    "thumbv7neon-unknown-linux-musleabihf",
+    "thumbv8m.base-none-eabi",
+    "thumbv8m.main-none-eabi",
+    "thumbv8m.main-none-eabihf",
+    "wasm32-experimental-emscripten",
+    "wasm32-unknown-emscripten",
+    "wasm32-unknown-unknown",
+    "wasm32-wasi",
+    "wasm64-unknown-unknown",
+    "wasm64-wasi",
+    "x86_64-apple-darwin",
+    "x86_64-apple-ios",
+    "x86_64-apple-macosx10.7.0",
+    "x86_64-fortanix-unknown-sgx",
+    "x86_64-fuchsia",
+    "x86_64-linux-android",
+    "x86_64-linux-kernel",
+    "x86_64-pc-solaris",
+    "x86_64-pc-windows-gnu",
+    "x86_64-pc-windows-gnullvm",
+    "x86_64-pc-windows-msvc",
+    "x86_64-rumprun-netbsd",
+    "x86_64-sun-solaris",
+    "x86_64-unknown-bitrig",
+    "x86_64-unknown-cloudabi",
+    "x86_64-unknown-dragonfly",
+    "x86_64-unknown-freebsd",
+    "x86_64-unknown-haiku",
+    "x86_64-unknown-hermit",
+    "x86_64-unknown-hermit-kernel",
+    "x86_64-unknown-illumos",
+    "x86_64-unknown-l4re-uclibc",
+    "x86_64-unknown-linux-gnu",
+    "x86_64-unknown-linux-gnux32",
+    "x86_64-unknown-linux-musl",
+    "x86_64-unknown-netbsd",
+    "x86_64-unknown-openbsd",
+    "x86_64-unknown-redox",
+    "x86_64-unknown-uefi",
+    "x86_64-uwp-windows-gnu",
+    "x86_64-uwp-windows-msvc",
+    "x86_64-wrs-vxworks",
+];
+
+/// The number of triples `Triple::known_triples` yields, so that downstream
+/// crates can check that they're built against a version of this crate which
+/// knows enough targets.
+pub const KNOWN_TRIPLE_COUNT: usize = KNOWN_TRIPLES.len();
+
+/// Triples which aren't targets rustc knows but which should still parse and
+/// display back in the same form.
+#[cfg(test)]
+pub(crate) const ROUNDTRIP_FIXTURES: &[&str] = &[
+    "aarch64be-unknown-linux-gnu_ilp32",
+    "mips-unknown-linux-gnusf",
+    "mipsel-buildroot-linux-uclibc",
+    "mipsel-openwrt-linux-musl",
+    "mipsel-unknown-linux-muslsf",
+    "sparcv8-unknown-none-elf",
+    "thumbv8.1m.main-none-eabi",
+    "wasm32-unknown-none",
+    "x86_64-unknown-openbsd7.4",
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::triple::{FloatAbi, ValidationWarning};
     use alloc::string::ToString;
 
     #[test]
     fn roundtrip_known_triples() {
-        // This list is constructed from:
-        //  - targets emitted by "rustup target list"
-        //  - targets emitted by "rustc +nightly --print target-list"
-        //  - targets contributors have added
-        let targets = [
-            "aarch64-apple-ios",
-            "aarch64-fuchsia",
-            "aarch64-linux-android",
-            "aarch64-pc-windows-msvc",
-            "aarch64-unknown-cloudabi",
-            "aarch64-unknown-freebsd",
-            "aarch64-unknown-hermit",
-            "aarch64-unknown-linux-gnu",
-            "aarch64-unknown-linux-musl",
-            "aarch64-unknown-netbsd",
-            "aarch64-unknown-none",
-            "aarch64-unknown-none-softfloat",
-            "aarch64-unknown-openbsd",
-            "aarch64-unknown-redox",
-	    "aarch64-unknown-optee-trustzone",
-            "aarch64-uwp-windows-msvc",
-            "aarch64-wrs-vxworks",
-            "amdgcn-amd-amdhsa",
-            "amdgcn-amd-amdhsa-amdgiz",
-            "armebv7r-none-eabi",
-            "armebv7r-none-eabihf",
-            "arm-linux-androideabi",
-            "arm-unknown-linux-gnueabi",
-            "arm-unknown-linux-gnueabihf",
-            "arm-unknown-linux-musleabi",
-            "arm-unknown-linux-musleabihf",
-            "armv4t-unknown-linux-gnueabi",
-            "armv5te-unknown-linux-gnueabi",
-            "armv5te-unknown-linux-musleabi",
-            "armv6-unknown-freebsd",
-            "armv6-unknown-netbsd-eabihf",
-            "armv7a-none-eabi",
-            "armv7a-none-eabihf",
-            "armv7-apple-ios",
-            "armv7-linux-androideabi",
-            "armv7r-none-eabi",
-            "armv7r-none-eabihf",
-            "armv7s-apple-ios",
-            "armv7-unknown-cloudabi-eabihf",
-            "armv7-unknown-freebsd",
-            "armv7-unknown-linux-gnueabi",
-            "armv7-unknown-linux-gnueabihf",
-            "armv7-unknown-linux-musleabi",
-            "armv7-unknown-linux-musleabihf",
-            "armv7-unknown-netbsd-eabihf",
-            "armv7-wrs-vxworks-eabihf",
-            "asmjs-unknown-emscripten",
-            "hexagon-unknown-linux-musl",
-            "i386-apple-ios",
-            "i586-pc-windows-msvc",
-            "i586-unknown-linux-gnu",
-            "i586-unknown-linux-musl",
-            "i686-apple-darwin",
-            "i686-linux-android",
-            "i686-apple-macosx10.7.0",
-            "i686-pc-windows-gnu",
-            "i686-pc-windows-msvc",
-            "i686-unknown-cloudabi",
-            "i686-unknown-dragonfly",
-            "i686-unknown-freebsd",
-            "i686-unknown-haiku",
-            "i686-unknown-linux-gnu",
-            "i686-unknown-linux-musl",
-            "i686-unknown-netbsd",
-            "i686-unknown-openbsd",
-            "i686-unknown-uefi",
-            "i686-uwp-windows-gnu",
-            "i686-uwp-windows-msvc",
-            "i686-wrs-vxworks",
-            "mips64el-unknown-linux-gnuabi64",
-            "mips64el-unknown-linux-muslabi64",
-            "mips64-unknown-linux-gnuabi64",
-            "mips64-unknown-linux-muslabi64",
-            "mipsel-sony-psp",
-            "mipsel-unknown-linux-gnu",
-            "mipsel-unknown-linux-musl",
-            "mipsel-unknown-linux-uclibc",
-            "mipsisa32r6el-unknown-linux-gnu",
-            "mipsisa32r6-unknown-linux-gnu",
-            "mipsisa64r6el-unknown-linux-gnuabi64",
-            "mipsisa64r6-unknown-linux-gnuabi64",
-            "mips-unknown-linux-gnu",
-            "mips-unknown-linux-musl",
-            "mips-unknown-linux-uclibc",
-            "msp430-none-elf",
-            "nvptx64-nvidia-cuda",
-            "powerpc64le-unknown-linux-gnu",
-            "powerpc64le-unknown-linux-musl",
-            "powerpc64-unknown-freebsd",
-            "powerpc64-unknown-linux-gnu",
-            "powerpc64-unknown-linux-musl",
-            "powerpc64-wrs-vxworks",
-            "powerpc-unknown-linux-gnu",
-            "powerpc-unknown-linux-gnuspe",
-            "powerpc-unknown-linux-musl",
-            "powerpc-unknown-netbsd",
-            "powerpc-wrs-vxworks",
-            "powerpc-wrs-vxworks-spe",
-            "riscv32imac-unknown-none-elf",
-            "riscv32imc-unknown-none-elf",
-            "riscv32i-unknown-none-elf",
-            "riscv64gc-unknown-linux-gnu",
-            "riscv64gc-unknown-none-elf",
-            "riscv64imac-unknown-none-elf",
-            "s390x-unknown-linux-gnu",
-            "sparc64-unknown-linux-gnu",
-            "sparc64-unknown-netbsd",
-            "sparc64-unknown-openbsd",
-            "sparc-unknown-linux-gnu",
-            "sparcv9-sun-solaris",
-            "thumbv6m-none-eabi",
-            "thumbv7a-pc-windows-msvc",
-            "thumbv7a-uwp-windows-msvc",
-            "thumbv7em-none-eabi",
-            "thumbv7em-none-eabihf",
-            "thumbv7m-none-eabi",
-            "thumbv7neon-linux-androideabi",
-            "thumbv7neon-unknown-linux-gnueabihf",
-            "thumbv7neon-unknown-linux-musleabihf",
-            "thumbv8m.base-none-eabi",
-            "thumbv8m.main-none-eabi",
-            "thumbv8m.main-none-eabihf",
-            "wasm32-experimental-emscripten",
-            "wasm32-unknown-emscripten",
-            "wasm32-unknown-unknown",
-            "wasm64-unknown-unknown",
-            "wasm32-wasi",
-            "wasm64-wasi",
-            "x86_64-apple-darwin",
-            "x86_64-apple-ios",
-            "x86_64-fortanix-unknown-sgx",
-            "x86_64-fuchsia",
-            "x86_64-linux-android",
-            "x86_64-linux-kernel",
-            "x86_64-apple-macosx10.7.0",
-            "x86_64-pc-solaris",
-            "x86_64-pc-windows-gnu",
-            "x86_64-pc-windows-msvc",
-            "x86_64-rumprun-netbsd",
-            "x86_64-sun-solaris",
-            "x86_64-unknown-bitrig",
-            "x86_64-unknown-cloudabi",
-            "x86_64-unknown-dragonfly",
-            "x86_64-unknown-freebsd",
-            "x86_64-unknown-haiku",
-            "x86_64-unknown-hermit",
-            "x86_64-unknown-hermit-kernel",
-            "x86_64-unknown-illumos",
-            "x86_64-unknown-l4re-uclibc",
-            "x86_64-unknown-linux-gnu",
-            "x86_64-unknown-linux-gnux32",
-            "x86_64-unknown-linux-musl",
-            "x86_64-unknown-netbsd",
-            "x86_64-unknown-openbsd",
-            "x86_64-unknown-redox",
-            "x86_64-unknown-uefi",
-            "x86_64-uwp-windows-gnu",
-            "x86_64-uwp-windows-msvc",
-            "x86_64-wrs-vxworks",
-        ];
-
-        for target in targets.iter() {
+        for target in KNOWN_TRIPLES.iter().chain(ROUNDTRIP_FIXTURES.iter()) {
             let t = Triple::from_str(target).expect("can't parse target");
             assert_ne!(t.architecture, Architecture::Unknown);
             assert_eq!(t.to_string(), *target);
+            assert_eq!(
+                Triple::try_new(
+                    t.architecture,
+                    t.vendor.clone(),
+                    t.operating_system,
+                    t.environment,
+                    t.binary_format,
+                ),
+                Ok(t.clone()),
+                "{} should be consistent",
+                target
+            );
+        }
+    }
+
+    /// Assert that `target` parses, and that its canonical form parses back to
+    /// the same `Triple`, without requiring the canonical form to match.
+    fn assert_idempotent_parse(target: &str) -> Triple {
+        let t = Triple::from_str(target).expect("can't parse target");
+        let canonical = t.to_string();
+        assert_eq!(
+            Triple::from_str(&canonical),
+            Ok(t.clone()),
+            "{} canonicalizes to {}, which parses differently",
+            target,
+            canonical
+        );
+        t
+    }
+
+    #[test]
+    fn architecture_aliases() {
+        for (target, architecture) in [
+            ("amd64-unknown-linux-gnu", Architecture::X86_64),
+            ("amd64-unknown-freebsd", Architecture::X86_64),
+            ("ppc64le-unknown-linux-gnu", Architecture::Powerpc64le),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(target).expect("can't parse target");
+            assert_eq!(t.architecture, *architecture, "{}", target);
+        }
+    }
+
+    #[test]
+    fn idempotent_parse_aliases() {
+        // These are aliases, so they don't display back in the same form,
+        // but their canonical forms should describe the same targets.
+        let aliases = [
+            ("amd64-unknown-freebsd", "x86_64-unknown-freebsd"),
+            ("amd64-unknown-linux-gnu", "x86_64-unknown-linux-gnu"),
+            ("arm64-apple-ios", "aarch64-apple-ios"),
+            ("i786-pc-linux-gnu", "i686-pc-linux-gnu"),
+            (
+                "mips32r6-unknown-linux-gnu",
+                "mipsisa32r6-unknown-linux-gnu",
+            ),
+            (
+                "mips32r6el-unknown-linux-gnu",
+                "mipsisa32r6el-unknown-linux-gnu",
+            ),
+            (
+                "mips64r6-unknown-linux-gnuabi64",
+                "mipsisa64r6-unknown-linux-gnuabi64",
+            ),
+            (
+                "mips64r6el-unknown-linux-gnuabi64",
+                "mipsisa64r6el-unknown-linux-gnuabi64",
+            ),
+            ("ppc64le-unknown-linux-gnu", "powerpc64le-unknown-linux-gnu"),
+        ];
+        for (alias, canonical) in aliases.iter() {
+            let t = assert_idempotent_parse(alias);
+            assert_eq!(t.to_string(), *canonical);
+        }
+
+        for target in KNOWN_TRIPLES.iter().chain(ROUNDTRIP_FIXTURES.iter()) {
+            assert_idempotent_parse(target);
         }
     }
 
@@ -1367,6 +2276,537 @@ mod tests {
         assert_eq!(t.binary_format, BinaryFormat::Elf);
     }
 
+    #[test]
+    fn sbf_solana_solana() {
+        let t = Triple::from_str("sbf-solana-solana").expect("can't parse target");
+        assert_eq!(t.architecture, Architecture::Sbf);
+        assert_eq!(t.vendor, Vendor::Solana);
+        assert_eq!(t.operating_system, OperatingSystem::Solana);
+        assert_eq!(t.environment, Environment::Unknown);
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+        assert_eq!(t.endianness(), Ok(Endianness::Little));
+        assert_eq!(t.pointer_width(), Ok(PointerWidth::U64));
+    }
+
+    #[test]
+    fn emscripten() {
+        let t = Triple::from_str("asmjs-unknown-emscripten").expect("can't parse target");
+        assert!(t.is_emscripten());
+        assert_eq!(t.binary_format, BinaryFormat::Unknown);
+
+        let t = Triple::from_str("wasm32-unknown-emscripten").expect("can't parse target");
+        assert!(t.is_emscripten());
+        assert_eq!(t.binary_format, BinaryFormat::Wasm);
+
+        let t = Triple::from_str("wasm32-wasi").expect("can't parse target");
+        assert!(!t.is_emscripten());
+    }
+
+    #[test]
+    fn s390x_unknown_linux_gnu() {
+        let t = Triple::from_str("s390x-unknown-linux-gnu").expect("can't parse target");
+        assert_eq!(t.architecture, Architecture::S390x);
+        assert_eq!(t.endianness(), Ok(Endianness::Big));
+        assert_eq!(t.pointer_width(), Ok(PointerWidth::U64));
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+        assert_eq!(t.s390x_has_vector(), Some(false));
+
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").expect("can't parse target");
+        assert_eq!(t.s390x_has_vector(), None);
+    }
+
+    #[test]
+    fn thumb() {
+        let t = Triple::from_str("thumbv7em-none-eabi").expect("can't parse target");
+        assert!(t.is_thumb());
+        assert_eq!(t.validate(), Ok(()));
+
+        let t = Triple::from_str("armv7-unknown-linux-gnueabihf").expect("can't parse target");
+        assert!(!t.is_thumb());
+        assert_eq!(t.validate(), Ok(()));
+
+        let t = Triple::from_str("thumbv7m-unknown-linux-gnueabi").expect("can't parse target");
+        assert!(t.is_thumb());
+        assert_eq!(
+            t.validate(),
+            Err(ValidationWarning::MProfileWithOperatingSystem)
+        );
+
+        let t = Triple::from_str("wasm32-unknown-unknown-macho").expect("can't parse target");
+        assert_eq!(t.validate(), Err(ValidationWarning::WasmWithMacho));
+        let t = Triple::from_str("wasm32-unknown-unknown").expect("can't parse target");
+        assert_eq!(t.validate(), Ok(()));
+
+        let t = Triple::from_str("msp430-none-elf").expect("can't parse target");
+        assert_eq!(t.validate(), Ok(()));
+        let t = Triple {
+            environment: Environment::Gnuabi64,
+            ..t
+        };
+        assert_eq!(
+            t.validate(),
+            Err(ValidationWarning::Msp430With64BitEnvironment)
+        );
+
+        // Thumb-mode A-profile cores run Linux just fine.
+        let t =
+            Triple::from_str("thumbv7neon-unknown-linux-gnueabihf").expect("can't parse target");
+        assert!(t.is_thumb());
+        assert_eq!(t.validate(), Ok(()));
+    }
+
+    #[test]
+    fn bsd_versions() {
+        let t = Triple::from_str("x86_64-unknown-openbsd7.4").expect("can't parse target");
+        assert_eq!(
+            t.operating_system,
+            OperatingSystem::Openbsd(Some(OsVersion {
+                major: 7,
+                minor: Some(4)
+            }))
+        );
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+
+        let t = Triple::from_str("aarch64-unknown-freebsd13.2").expect("can't parse target");
+        assert_eq!(
+            t.operating_system,
+            OperatingSystem::Freebsd(Some(OsVersion {
+                major: 13,
+                minor: Some(2)
+            }))
+        );
+        assert_eq!(t.to_string(), "aarch64-unknown-freebsd13.2");
+
+        let t = Triple::from_str("x86_64-unknown-netbsd").expect("can't parse target");
+        assert_eq!(t.operating_system, OperatingSystem::Netbsd(None));
+
+        let t = Triple::from_str("x86_64-unknown-freebsd12").expect("can't parse target");
+        assert_eq!(
+            t.operating_system,
+            OperatingSystem::Freebsd(Some(OsVersion {
+                major: 12,
+                minor: None
+            }))
+        );
+        assert_eq!(t.to_string(), "x86_64-unknown-freebsd12");
+
+        assert!(Triple::from_str("x86_64-unknown-openbsd7.").is_err());
+        assert!(Triple::from_str("x86_64-unknown-openbsd7.4.1").is_err());
+        assert!(Triple::from_str("x86_64-unknown-openbsdx").is_err());
+        assert!(Triple::from_str("x86_64-unknown-freebsd+12").is_err());
+        assert!(Triple::from_str("x86_64-unknown-freebsd012").is_err());
+        assert!(Triple::from_str("x86_64-unknown-freebsd12.01").is_err());
+        assert!(Triple::from_str("x86_64-unknown-freebsd12.+1").is_err());
+        assert!(Triple::from_str("x86_64-apple-macosx10.07.0").is_err());
+
+        let t = Triple::from_str("x86_64-unknown-freebsd12.0").expect("can't parse target");
+        assert_eq!(t.to_string(), "x86_64-unknown-freebsd12.0");
+    }
+
+    #[test]
+    fn solarish() {
+        let t = Triple::from_str("x86_64-pc-solaris").expect("can't parse target");
+        assert_eq!(t.vendor, Vendor::Pc);
+        assert_eq!(t.operating_system, OperatingSystem::Solaris);
+        assert!(t.is_solarish());
+        assert_eq!(t.to_string(), "x86_64-pc-solaris");
+
+        let t = Triple::from_str("x86_64-unknown-illumos").expect("can't parse target");
+        assert_eq!(t.vendor, Vendor::Unknown);
+        assert_eq!(t.operating_system, OperatingSystem::Illumos);
+        assert!(t.is_solarish());
+        assert_eq!(t.to_string(), "x86_64-unknown-illumos");
+
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").expect("can't parse target");
+        assert!(!t.is_solarish());
+    }
+
+    #[test]
+    fn valid_environments() {
+        let windows = OperatingSystem::Windows.valid_environments();
+        assert!(windows.contains(&Environment::Msvc));
+        assert!(windows.contains(&Environment::Gnu));
+        assert!(!windows.contains(&Environment::Musl));
+
+        let none = OperatingSystem::None_.valid_environments();
+        assert!(none.contains(&Environment::Eabihf));
+        assert!(!none.contains(&Environment::Gnu));
+    }
+
+    #[test]
+    fn families() {
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").expect("can't parse target");
+        assert!(t.operating_system.is_like_unix());
+        assert!(!t.operating_system.is_like_windows());
+        assert!(!t.operating_system.is_like_darwin());
+        assert_eq!(t.target_family(), Some("unix"));
+
+        let t = Triple::from_str("x86_64-pc-windows-msvc").expect("can't parse target");
+        assert!(!t.operating_system.is_like_unix());
+        assert!(t.operating_system.is_like_windows());
+        assert_eq!(t.target_family(), Some("windows"));
+
+        let t = Triple::from_str("x86_64-apple-darwin").expect("can't parse target");
+        assert!(t.operating_system.is_like_unix());
+        assert!(t.operating_system.is_like_darwin());
+        assert_eq!(t.target_family(), Some("unix"));
+
+        let t = Triple::from_str("wasm32-unknown-unknown").expect("can't parse target");
+        assert!(!t.operating_system.is_like_wasm());
+        assert_eq!(t.target_family(), Some("wasm"));
+
+        let t = Triple::from_str("thumbv7em-none-eabihf").expect("can't parse target");
+        assert_eq!(t.target_family(), None);
+    }
+
+    #[test]
+    fn wasm_component() {
+        let t = Triple::try_new(
+            Architecture::Wasm32,
+            Vendor::Unknown,
+            OperatingSystem::Wasi,
+            Environment::Unknown,
+            BinaryFormat::WasmComponent,
+        )
+        .expect("components are a valid wasm binary format");
+        assert_eq!(t.to_string(), "wasm32-wasi-wasmcomponent");
+        assert_eq!(Triple::from_str("wasm32-wasi-wasmcomponent"), Ok(t));
+
+        // Core modules remain the default.
+        let t = Triple::from_str("wasm32-wasi").expect("can't parse target");
+        assert_eq!(t.binary_format, BinaryFormat::Wasm);
+    }
+
+    #[test]
+    fn amdgcn_gfx() {
+        let t = Triple::from_str("amdgcn-amd-amdhsa--gfx900").expect("can't parse target");
+        assert_eq!(t.architecture, Architecture::AmdGcn);
+        assert_eq!(t.operating_system, OperatingSystem::AmdHsa);
+        assert_eq!(t.environment, Environment::AmdGfx(AmdGfxVersion(0x900)));
+        assert_eq!(t.to_string(), "amdgcn-amd-amdhsa--gfx900");
+
+        let t = Triple::from_str("amdgcn-amd-amdhsa--gfx90a").expect("can't parse target");
+        assert_eq!(t.environment, Environment::AmdGfx(AmdGfxVersion(0x90a)));
+        assert_eq!(t.to_string(), "amdgcn-amd-amdhsa--gfx90a");
+
+        // The single-dash spelling is accepted too.
+        let t = Triple::from_str("amdgcn-amd-amdhsa-gfx1030").expect("can't parse target");
+        assert_eq!(t.environment, Environment::AmdGfx(AmdGfxVersion(0x1030)));
+        assert_eq!(t.to_string(), "amdgcn-amd-amdhsa--gfx1030");
+
+        assert!(Triple::from_str("amdgcn-amd-amdhsa--gfx0900").is_err());
+        assert!(Triple::from_str("amdgcn-amd-amdhsa--gfx9").is_err());
+        assert!(Triple::from_str("amdgcn-amd-amdhsa--gfx90A").is_err());
+        assert!(Triple::from_str("amdgcn-amd-amdhsa--").is_err());
+    }
+
+    #[test]
+    fn cmse() {
+        for arch in [
+            ArmArchitecture::Armv8mBase,
+            ArmArchitecture::Armv8mMain,
+            ArmArchitecture::Armv8_1mMain,
+            ArmArchitecture::Thumbv8mBase,
+            ArmArchitecture::Thumbv8mMain,
+            ArmArchitecture::Thumbv8_1mMain,
+        ]
+        .iter()
+        {
+            assert!(arch.has_cmse(), "{} should have CMSE", arch);
+            assert!(arch.is_mprofile());
+        }
+        assert!(!ArmArchitecture::Thumbv7m.has_cmse());
+        assert!(!ArmArchitecture::Armv8a.has_cmse());
+    }
+
+    #[test]
+    fn none_environment() {
+        let t = Triple::from_str("riscv32-unknown-none-none").expect("can't parse target");
+        assert_eq!(t.operating_system, OperatingSystem::None_);
+        assert_eq!(t.environment, Environment::None_);
+        assert_eq!(t.to_string(), "riscv32-unknown-none-none");
+
+        // A lone `none` is still the operating system.
+        let t = Triple::from_str("msp430-none-elf").expect("can't parse target");
+        assert_eq!(t.operating_system, OperatingSystem::None_);
+        assert_eq!(t.environment, Environment::Unknown);
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+
+        let t = Triple::from_str("thumbv7m-none-eabi").expect("can't parse target");
+        assert_eq!(t.operating_system, OperatingSystem::None_);
+        assert_eq!(t.environment, Environment::Eabi);
+    }
+
+    #[test]
+    fn pointer_width_const() {
+        const X86_64: Option<PointerWidth> = Architecture::X86_64.pointer_width_const();
+        const THUMB: Option<PointerWidth> =
+            Architecture::Arm(ArmArchitecture::Thumbv7em).pointer_width_const();
+        const UNKNOWN: Option<PointerWidth> = Architecture::Unknown.pointer_width_const();
+        assert_eq!(X86_64, Some(PointerWidth::U64));
+        assert_eq!(THUMB, Some(PointerWidth::U32));
+        assert_eq!(UNKNOWN, None);
+    }
+
+    #[test]
+    fn deprecated() {
+        assert!(OperatingSystem::Bitrig.is_deprecated());
+        assert!(OperatingSystem::Cloudabi.is_deprecated());
+        assert!(OperatingSystem::Nebulet.is_deprecated());
+        assert!(!OperatingSystem::Linux.is_deprecated());
+
+        assert_eq!(
+            OperatingSystem::Cloudabi.replacement(),
+            Some(OperatingSystem::Wasi)
+        );
+        assert_eq!(
+            OperatingSystem::Bitrig.replacement(),
+            Some(OperatingSystem::Openbsd(None))
+        );
+        assert_eq!(OperatingSystem::Nebulet.replacement(), None);
+        assert_eq!(OperatingSystem::Linux.replacement(), None);
+
+        // Deprecated operating systems still parse.
+        let t = Triple::from_str("x86_64-unknown-bitrig").expect("can't parse target");
+        assert!(t.operating_system.is_deprecated());
+    }
+
+    #[test]
+    fn sparcv8() {
+        let t = Triple::from_str("sparcv8-unknown-none-elf").expect("can't parse target");
+        assert_eq!(t.architecture, Architecture::Sparcv8);
+        assert_eq!(t.pointer_width(), Ok(PointerWidth::U32));
+        assert_eq!(t.endianness(), Ok(Endianness::Big));
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+    }
+
+    #[test]
+    fn l4re() {
+        let t = Triple::from_str("x86_64-unknown-l4re-uclibc").expect("can't parse target");
+        assert_eq!(t.operating_system, OperatingSystem::L4re);
+        assert_eq!(t.environment, Environment::Uclibc);
+        assert!(t.operating_system.is_microkernel());
+        assert!(!t.has_std());
+        assert!(!t.supports_dynamic_linking());
+        assert!(!OperatingSystem::Linux.is_microkernel());
+    }
+
+    #[test]
+    fn wasm64() {
+        for target in ["wasm64-unknown-unknown", "wasm64-wasi"].iter() {
+            let t = Triple::from_str(target).expect("can't parse target");
+            assert_eq!(t.architecture, Architecture::Wasm64);
+            assert_eq!(t.binary_format, BinaryFormat::Wasm);
+            assert_eq!(t.pointer_width(), Ok(PointerWidth::U64));
+            assert!(t.is_memory64());
+        }
+        let t = Triple::from_str("wasm32-wasi").expect("can't parse target");
+        assert!(!t.is_memory64());
+    }
+
+    #[test]
+    fn wasm_none() {
+        for target in ["wasm32-unknown-none", "wasm64-unknown-none"].iter() {
+            let t = Triple::from_str(target).expect("can't parse target");
+            assert_eq!(t.operating_system, OperatingSystem::None_);
+            assert_eq!(t.binary_format, BinaryFormat::Wasm);
+            assert_eq!(t.to_string(), *target);
+        }
+    }
+
+    #[test]
+    fn pre_armv6() {
+        assert!(!ArmArchitecture::Armv4.supports_interworking());
+        assert!(ArmArchitecture::Armv4t.supports_interworking());
+        assert!(ArmArchitecture::Armv5te.supports_interworking());
+        assert!(ArmArchitecture::Armv7a.supports_interworking());
+        assert!(!ArmArchitecture::Thumbv7m.supports_interworking());
+
+        // These predate hard-float ABIs, so their environments are soft-float
+        // EABI, and nothing is inferred when the environment is omitted.
+        for target in [
+            "armv4t-unknown-linux-gnueabi",
+            "armv5te-unknown-linux-gnueabi",
+            "armv5te-unknown-linux-musleabi",
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(target).expect("can't parse target");
+            assert_eq!(t.binary_format, BinaryFormat::Elf);
+            assert!(t.environment.is_soft_float());
+            assert!(!t.is_thumb());
+        }
+        let t = Triple::from_str("armv5te-unknown-linux").expect("can't parse target");
+        assert_eq!(t.environment, Environment::Unknown);
+        assert_eq!(t.float_abi(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn custom_operating_systems() {
+        // The registry is shared by all tests, so use a name nothing else
+        // uses.
+        const NAME: &str = "lexicontestos";
+
+        assert!(Triple::from_str("x86_64-unknown-lexicontestos").is_err());
+        let vendor = CustomVendor::Static(NAME);
+        assert!(vendor.is_valid());
+        let before = Triple::from_str("x86_64-lexicontestos-linux").expect("can't parse target");
+
+        assert_eq!(register_custom_os(NAME), Ok(()));
+        assert_eq!(register_custom_os(NAME), Ok(()));
+        let t = Triple::from_str("x86_64-unknown-lexicontestos").expect("can't parse target");
+        assert_eq!(t.operating_system, OperatingSystem::Custom(NAME));
+        assert_eq!(t.binary_format, BinaryFormat::Unknown);
+        assert_eq!(t.to_string(), "x86_64-unknown-lexicontestos");
+
+        // In the vendor position, the name is still a custom vendor.
+        assert!(vendor.is_valid());
+        let after = Triple::from_str("x86_64-lexicontestos-linux").expect("can't parse target");
+        assert_eq!(after, before);
+        assert_eq!(after.vendor, Vendor::Custom(vendor));
+        assert_eq!(after.operating_system, OperatingSystem::Linux);
+
+        assert!(register_custom_os("").is_err());
+        assert!(register_custom_os("Serenity").is_err());
+        assert!(register_custom_os("linux").is_err());
+        assert!(register_custom_os("apple").is_err());
+        assert!(register_custom_os("x86_64").is_err());
+        assert!(register_custom_os("gnu").is_err());
+        assert!(register_custom_os("sérénity").is_err());
+    }
+
+    #[test]
+    fn netbsd_eabihf() {
+        // An EABI environment on a real operating system still gets the OS's
+        // binary format, rather than the bare-metal handling of `eabihf`.
+        for (target, arch) in [
+            ("armv6-unknown-netbsd-eabihf", ArmArchitecture::Armv6),
+            ("armv7-unknown-netbsd-eabihf", ArmArchitecture::Armv7),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(target).expect("can't parse target");
+            assert_eq!(t.architecture, Architecture::Arm(*arch));
+            assert_eq!(t.vendor, Vendor::Unknown);
+            assert_eq!(t.operating_system, OperatingSystem::Netbsd(None));
+            assert_eq!(t.environment, Environment::Eabihf);
+            assert_eq!(t.binary_format, BinaryFormat::Elf);
+            assert_eq!(t.float_abi(), Some(FloatAbi::Hard));
+            assert_eq!(t.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn arm_version() {
+        assert!(ArmArchitecture::Armv8_2a.version() > ArmArchitecture::Armv8a.version());
+        assert!(ArmArchitecture::Armv8a.version() > ArmArchitecture::Armv7a.version());
+        assert!(ArmArchitecture::Armv7a.version() > ArmArchitecture::Armv5te.version());
+        assert_eq!(
+            ArmArchitecture::Thumbv8_1mMain.version(),
+            Some(ArmVersion {
+                major: 8,
+                minor: 1,
+                profile: ArmProfile::M,
+            })
+        );
+        assert_eq!(
+            ArmArchitecture::Armv7a.version(),
+            ArmArchitecture::Thumbv7neon.version()
+        );
+        assert_eq!(ArmArchitecture::Arm.version(), None);
+    }
+
+    #[test]
+    fn neon() {
+        for (target, neon) in [
+            ("thumbv7neon-unknown-linux-gnueabihf", true),
+            ("armv7s-apple-ios", true),
+            ("armv8a-none-eabi", true),
+            ("thumbv7m-none-eabi", false),
+            ("armv7-unknown-linux-gnueabihf", false),
+            ("armv5te-unknown-linux-gnueabi", false),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(target).expect("can't parse target");
+            match t.architecture {
+                Architecture::Arm(arm) => assert_eq!(arm.has_neon(), *neon, "{}", target),
+                _ => panic!("{} isn't 32-bit ARM", target),
+            }
+        }
+        assert!(Aarch64Architecture::Aarch64.has_neon());
+        assert!(Aarch64Architecture::Aarch64be.has_neon());
+    }
+
+    #[test]
+    fn all_operating_systems() {
+        let all = OperatingSystem::all();
+        for os in all.iter() {
+            // Every listed operating system parses back from its name.
+            assert_eq!(OperatingSystem::from_str(&os.to_string()), Ok(*os));
+            let format = default_binary_format_for(Architecture::X86_64, *os, Environment::Unknown);
+            let expected = match os {
+                OperatingSystem::Windows => BinaryFormat::Coff,
+                OperatingSystem::Darwin | OperatingSystem::Ios => BinaryFormat::Macho,
+                OperatingSystem::Linux | OperatingSystem::Freebsd(_) => BinaryFormat::Elf,
+                _ => continue,
+            };
+            assert_eq!(format, expected, "{}", os);
+        }
+        assert!(all.contains(&OperatingSystem::Linux));
+        assert_eq!(
+            default_binary_format_for(
+                Architecture::Wasm32,
+                OperatingSystem::Wasi,
+                Environment::Unknown
+            ),
+            BinaryFormat::Wasm
+        );
+        assert_eq!(
+            default_binary_format_for(
+                Architecture::Arm(ArmArchitecture::Thumbv7em),
+                OperatingSystem::None_,
+                Environment::Eabihf
+            ),
+            BinaryFormat::Elf
+        );
+    }
+
+    #[test]
+    fn darwin_family() {
+        for (target, os) in [
+            ("x86_64-apple-darwin", OperatingSystem::Darwin),
+            (
+                "x86_64-apple-macosx10.7.0",
+                OperatingSystem::MacOSX {
+                    major: 10,
+                    minor: 7,
+                    patch: 0,
+                },
+            ),
+            ("aarch64-apple-ios", OperatingSystem::Ios),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(target).expect("can't parse target");
+            assert_eq!(t.operating_system, *os);
+            assert!(t.operating_system.is_darwin_family());
+            assert_eq!(t.binary_format, BinaryFormat::Macho);
+        }
+        assert!(!OperatingSystem::Linux.is_darwin_family());
+        for os in [
+            OperatingSystem::Darwin,
+            OperatingSystem::Ios,
+            OperatingSystem::Linux,
+            OperatingSystem::Windows,
+        ]
+        .iter()
+        {
+            assert_eq!(os.is_darwin_family(), os.is_like_darwin(), "{}", os);
+        }
+    }
+
     #[test]
     fn custom_vendors() {
         // Test various invalid cases.
@@ -1426,6 +2866,43 @@ mod tests {
         assert_eq!(t.environment, Environment::Unknown);
         assert_eq!(t.binary_format, BinaryFormat::Elf);
         assert_eq!(t.to_string(), "x86_64-customvendor-linux");
+        match t.vendor {
+            Vendor::Custom(custom) => assert!(custom.is_valid()),
+            _ => unreachable!(),
+        }
+        assert!(!CustomVendor::Static("bad vendor!").is_valid());
+        assert!(!CustomVendor::Static("linux").is_valid());
+        assert!(CustomVendor::Static("customvendor").is_valid());
+
+        // Test the same hazards against `CustomVendor::try_new` directly.
+        for bad in [
+            "",
+            "42",
+            "__customvendor__",
+            "^",
+            " ",
+            "CustomVendor",
+            "linux",
+            "x86_64",
+            "elf",
+            "gnu",
+            "pc",
+            "𝓬𝓾𝓼𝓽𝓸𝓶𝓿𝓮𝓷𝓭𝓸𝓻",
+            "ćúśtőḿvéńdőŕ",
+            "customvendοr",
+            "ﬃ",
+            "custom‍vendor",
+            "﻿customvendor",
+        ]
+        .iter()
+        {
+            assert!(CustomVendor::try_new(bad).is_err(), "{:?}", bad);
+        }
+        assert_eq!(
+            CustomVendor::try_new("customvendor"),
+            Ok(CustomVendor::Static("customvendor"))
+        );
+        assert!(CustomVendor::try_new("ffi").is_ok());
 
         let t =
             Triple::from_str("x86_64-customvendor").expect("can't parse target with custom vendor");
@@ -3,10 +3,15 @@
 use crate::data_model::CDataModel;
 use crate::parse_error::ParseError;
 use crate::targets::{
-    default_binary_format, Architecture, ArmArchitecture, BinaryFormat, Environment,
-    OperatingSystem, Vendor,
+    default_binary_format, Aarch64Architecture, AmdGfxVersion, Architecture, ArchitectureFamily,
+    ArmArchitecture, ArmProfile, BinaryFormat, Environment, Mips32Architecture, Mips64Architecture,
+    OperatingSystem, OsVersion, Riscv32Architecture, Riscv64Architecture, Vendor,
+    X86_32Architecture, KNOWN_TRIPLES,
 };
 use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use core::fmt;
 use core::str::FromStr;
 
@@ -18,6 +23,36 @@ pub enum Endianness {
     Big,
 }
 
+impl Endianness {
+    /// Return the endianness of the current host, as reported by
+    /// `cfg!(target_endian)`.
+    pub const fn host() -> Self {
+        if cfg!(target_endian = "big") {
+            Self::Big
+        } else {
+            Self::Little
+        }
+    }
+
+    /// Test if this is little-endian.
+    pub fn is_little(self) -> bool {
+        self == Self::Little
+    }
+
+    /// Test if this is big-endian.
+    pub fn is_big(self) -> bool {
+        self == Self::Big
+    }
+
+    /// Return the other endianness.
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Little => Self::Big,
+            Self::Big => Self::Little,
+        }
+    }
+}
+
 /// The width of a pointer (in the default address space).
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[allow(missing_docs)]
@@ -68,6 +103,240 @@ pub enum CallingConvention {
     /// Windows documentation often just calls the Windows x64 calling convention
     /// (though the compiler still recognizes "fastcall" as an alias for it).
     WindowsFastcall,
+
+    /// The original 64-bit PowerPC ELF ABI, which calls through function
+    /// descriptors and always reserves a parameter save area. It's used by
+    /// big-endian PowerPC64 Linux with glibc.
+    PowerPc64ElfV1,
+
+    /// The revised 64-bit PowerPC ELF ABI, which drops function descriptors
+    /// and only reserves a parameter save area when it's needed. It's used by
+    /// all little-endian PowerPC64 targets and by newer big-endian ones.
+    PowerPc64ElfV2,
+}
+
+/// The RISC-V integer and floating-point calling convention, as named by the
+/// `-mabi` flag of GCC and Clang.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum RiscvFloatAbi {
+    Ilp32,
+    /// The ABI for the RV32E embedded base, which passes arguments in fewer
+    /// registers than `Ilp32`.
+    Ilp32e,
+    Ilp32f,
+    Ilp32d,
+    Lp64,
+    Lp64f,
+    Lp64d,
+}
+
+impl fmt::Display for RiscvFloatAbi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            Self::Ilp32 => "ilp32",
+            Self::Ilp32e => "ilp32e",
+            Self::Ilp32f => "ilp32f",
+            Self::Ilp32d => "ilp32d",
+            Self::Lp64 => "lp64",
+            Self::Lp64f => "lp64f",
+            Self::Lp64d => "lp64d",
+        };
+        f.write_str(s)
+    }
+}
+
+/// How floating-point values are passed between functions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FloatAbi {
+    /// Floating-point values are passed in floating-point registers.
+    Hard,
+    /// Floating-point values are passed in integer registers or memory.
+    Soft,
+    /// PowerPC's Signal Processing Engine, which computes with floats in
+    /// the general-purpose registers, and passes them there.
+    Spe,
+}
+
+/// A C standard library implementation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Libc {
+    /// The GNU C Library.
+    Glibc,
+    /// musl libc.
+    Musl,
+    /// uClibc and uClibc-ng.
+    Uclibc,
+    /// Android's Bionic.
+    Bionic,
+    /// Microsoft's C runtime, used by both MSVC and MinGW.
+    Msvcrt,
+    /// Redox's relibc.
+    Relibc,
+    /// wasi-libc.
+    Wasi,
+}
+
+/// Whether code keeps a frame pointer in a dedicated register.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FramePointer {
+    /// The frame pointer may be omitted, as with `-fomit-frame-pointer`.
+    MayOmit,
+    /// Functions which call other functions keep a frame pointer.
+    NonLeaf,
+    /// All functions keep a frame pointer.
+    All,
+}
+
+/// The toolchain family a Windows target is built with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WindowsToolchain {
+    /// Microsoft's toolchain, with `link.exe` and the MSVC runtime.
+    Msvc,
+    /// MinGW-w64 with the GNU toolchain.
+    Gnu,
+    /// MinGW-w64 with the LLVM toolchain, such as `lld` and `libc++`.
+    GnuLlvm,
+}
+
+/// The kind of linker a target links with, which determines the command-line
+/// syntax to use.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LinkerFlavor {
+    /// A GNU-compatible linker driven through the C compiler, such as `cc`
+    /// running `ld.bfd`, `gold`, or `lld`.
+    Gnu,
+    /// Microsoft's `link.exe`, or `lld-link`.
+    Msvc,
+    /// Apple's `ld64`, driven through the C compiler.
+    Ld64,
+    /// `wasm-ld`.
+    Wasm,
+    /// A GNU-compatible linker run directly, as for bare-metal targets with
+    /// no C compiler driver.
+    Ld,
+}
+
+/// The model used to access thread-local storage, as in the ELF TLS ABI.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum TlsModel {
+    GeneralDynamic,
+    LocalDynamic,
+    InitialExec,
+    LocalExec,
+}
+
+/// The format of debug information emitted for a target.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DebugFormat {
+    /// DWARF, stored in the object files or alongside them.
+    Dwarf,
+    /// Microsoft's CodeView, collected into PDB files by the linker.
+    CodeView,
+}
+
+/// Whether and how debug information is split out of the final artifact, as
+/// in rustc's `-C split-debuginfo`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SplitDebuginfo {
+    /// Debug information stays in the linked artifact.
+    Off,
+    /// Debug information is collected into a single separate file, such as
+    /// a dSYM bundle or a PDB file.
+    Packed,
+    /// Debug information is left in separate per-object files, such as
+    /// split DWARF `.dwo` files or Apple's object files.
+    Unpacked,
+}
+
+/// How a target unwinds the stack when a panic or exception propagates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UnwindStrategy {
+    /// Table-based unwinding driven by DWARF call frame information, or on
+    /// 32-bit ARM by the equivalent EHABI tables.
+    DwarfCfi,
+    /// Windows structured exception handling.
+    Seh,
+    /// `setjmp`/`longjmp`-based unwinding, which registers each frame as
+    /// it's entered.
+    Sjlj,
+    /// No unwinding; panics abort.
+    Abort,
+}
+
+/// One of the five fields of a `Triple`, for tools which treat them
+/// uniformly.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TripleField {
+    /// The `architecture` field.
+    Architecture,
+    /// The `vendor` field.
+    Vendor,
+    /// The `operating_system` field.
+    OperatingSystem,
+    /// The `environment` field.
+    Environment,
+    /// The `binary_format` field.
+    BinaryFormat,
+}
+
+/// An error returned from `Triple::try_new` when the given fields don't
+/// describe a plausible target.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InconsistentTriple {
+    /// The binary format can't be produced for the given architecture and
+    /// operating system, such as `Macho` on Windows or `Elf` on wasm32.
+    IncompatibleBinaryFormat(BinaryFormat),
+    /// The environment doesn't belong to the given operating system, such as
+    /// `Msvc` on Linux.
+    IncompatibleEnvironment(Environment),
+}
+
+impl fmt::Display for InconsistentTriple {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::IncompatibleBinaryFormat(binary_format) => {
+                write!(fmt, "Incompatible binary format: {}", binary_format)
+            }
+            Self::IncompatibleEnvironment(environment) => {
+                write!(fmt, "Incompatible environment: {}", environment)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InconsistentTriple {}
+
+/// A reason why `Triple::validate` considers a triple suspicious.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationWarning {
+    /// An M-profile ARM architecture is paired with an operating system,
+    /// though M-profile cores have no MMU and essentially only run
+    /// bare-metal.
+    MProfileWithOperatingSystem,
+    /// A wasm architecture is paired with the Mach-O binary format, which
+    /// can't hold WebAssembly code.
+    WasmWithMacho,
+    /// The 16-bit msp430 architecture is paired with an environment naming a
+    /// 64-bit ABI, such as `gnuabi64`.
+    Msp430With64BitEnvironment,
+}
+
+impl fmt::Display for ValidationWarning {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MProfileWithOperatingSystem => {
+                write!(fmt, "M-profile architecture with an operating system")
+            }
+            Self::WasmWithMacho => write!(fmt, "wasm architecture with Mach-O binary format"),
+            Self::Msp430With64BitEnvironment => {
+                write!(fmt, "msp430 architecture with a 64-bit environment")
+            }
+        }
+    }
 }
 
 /// A target "triple". Historically such things had three fields, though they've
@@ -93,23 +362,35 @@ pub struct Triple {
 
 impl Triple {
     /// Return the endianness of this target's architecture.
+    #[allow(clippy::result_unit_err)]
     pub fn endianness(&self) -> Result<Endianness, ()> {
         self.architecture.endianness()
     }
 
-    /// Return the pointer width of this target's architecture.
+    /// Return the pointer width of this target, which is the architecture's
+    /// unless the environment names an ABI with a different width. For the
+    /// width of the registers, as with `gnux32`, use
+    /// `Architecture::register_width`.
+    #[allow(clippy::result_unit_err)]
     pub fn pointer_width(&self) -> Result<PointerWidth, ()> {
-        self.architecture.pointer_width()
+        match self.environment.pointer_width_override() {
+            Some(width) => Ok(width),
+            None => self.architecture.pointer_width(),
+        }
     }
 
     /// Return the default calling convention for the given target triple.
+    #[allow(clippy::result_unit_err)]
     pub fn default_calling_convention(&self) -> Result<CallingConvention, ()> {
+        if let Some(convention) = self.powerpc64_calling_convention() {
+            return Ok(convention);
+        }
         Ok(match self.operating_system {
             OperatingSystem::Bitrig
             | OperatingSystem::Cloudabi
             | OperatingSystem::Darwin
             | OperatingSystem::Dragonfly
-            | OperatingSystem::Freebsd
+            | OperatingSystem::Freebsd(_)
             | OperatingSystem::Fuchsia
             | OperatingSystem::Haiku
             | OperatingSystem::Hermit
@@ -117,8 +398,8 @@ impl Triple {
             | OperatingSystem::L4re
             | OperatingSystem::Linux
             | OperatingSystem::MacOSX { .. }
-            | OperatingSystem::Netbsd
-            | OperatingSystem::Openbsd
+            | OperatingSystem::Netbsd(_)
+            | OperatingSystem::Openbsd(_)
             | OperatingSystem::Redox
             | OperatingSystem::Solaris => CallingConvention::SystemV,
             OperatingSystem::Windows => CallingConvention::WindowsFastcall,
@@ -133,14 +414,40 @@ impl Triple {
         })
     }
 
+    /// Return which 64-bit PowerPC ELF ABI this triple uses, or `None` if it
+    /// isn't a 64-bit PowerPC ELF target.
+    ///
+    /// Little-endian targets always use ELFv2. Big-endian glibc Linux keeps
+    /// ELFv1, while musl and the BSDs have moved to ELFv2.
+    fn powerpc64_calling_convention(&self) -> Option<CallingConvention> {
+        if self.binary_format != BinaryFormat::Elf {
+            return None;
+        }
+        match self.architecture {
+            Architecture::Powerpc64le => Some(CallingConvention::PowerPc64ElfV2),
+            Architecture::Powerpc64 => match (self.operating_system, self.environment) {
+                (OperatingSystem::Linux, Environment::Musl)
+                | (OperatingSystem::Freebsd(_), _)
+                | (OperatingSystem::Openbsd(_), _) => Some(CallingConvention::PowerPc64ElfV2),
+                _ => Some(CallingConvention::PowerPc64ElfV1),
+            },
+            _ => None,
+        }
+    }
+
     /// The C data model for a given target. If the model is not known, returns `Err(())`.
+    #[allow(clippy::result_unit_err)]
     pub fn data_model(&self) -> Result<CDataModel, ()> {
         match self.pointer_width()? {
             PointerWidth::U64 => {
                 if self.operating_system == OperatingSystem::Windows {
                     Ok(CDataModel::LLP64)
-                } else if self.default_calling_convention() == Ok(CallingConvention::SystemV)
-                    || self.architecture == Architecture::Wasm64
+                } else if matches!(
+                    self.default_calling_convention(),
+                    Ok(CallingConvention::SystemV)
+                        | Ok(CallingConvention::PowerPc64ElfV1)
+                        | Ok(CallingConvention::PowerPc64ElfV2)
+                ) || self.architecture == Architecture::Wasm64
                 {
                     Ok(CDataModel::LP64)
                 } else {
@@ -165,6 +472,106 @@ impl Triple {
         }
     }
 
+    /// Construct a `Triple` from its fields, rejecting combinations which
+    /// can't describe a real target.
+    ///
+    /// `Unknown` is accepted in every field. Otherwise, the following
+    /// combinations are rejected:
+    ///
+    ///  - `Wasm` or `WasmComponent` with a non-wasm architecture, and any
+    ///    other binary format with a wasm architecture.
+    ///  - `Macho` with an operating system other than Darwin, iOS, or macOS,
+    ///    and any binary format other than `Macho` with those operating
+    ///    systems.
+    ///  - `Coff` with an operating system other than Windows or UEFI.
+    ///  - `Msvc` on anything but Windows, `Android` and `Androideabi` on
+    ///    anything but Linux, `Macabi` and `Sim` on anything but iOS, `Kernel`
+    ///    on anything but Linux or Hermit, `AmdGiz` and `AmdGfx` on anything but
+    ///    AMD HSA, `TrustZone` on anything but OP-TEE, and `Sgx` with a known
+    ///    operating system.
+    pub fn try_new(
+        architecture: Architecture,
+        vendor: Vendor,
+        operating_system: OperatingSystem,
+        environment: Environment,
+        binary_format: BinaryFormat,
+    ) -> Result<Self, InconsistentTriple> {
+        let triple = Self {
+            architecture,
+            vendor,
+            operating_system,
+            environment,
+            binary_format,
+        };
+        if !triple.has_compatible_binary_format() {
+            return Err(InconsistentTriple::IncompatibleBinaryFormat(binary_format));
+        }
+        if !triple.has_compatible_environment() {
+            return Err(InconsistentTriple::IncompatibleEnvironment(environment));
+        }
+        Ok(triple)
+    }
+
+    fn has_compatible_binary_format(&self) -> bool {
+        if self.binary_format == BinaryFormat::Unknown {
+            return true;
+        }
+
+        let is_wasm = matches!(
+            self.architecture,
+            Architecture::Wasm32 | Architecture::Wasm64
+        );
+        if is_wasm
+            != matches!(
+                self.binary_format,
+                BinaryFormat::Wasm | BinaryFormat::WasmComponent
+            )
+        {
+            return false;
+        }
+
+        let is_apple = matches!(
+            self.operating_system,
+            OperatingSystem::Darwin | OperatingSystem::Ios | OperatingSystem::MacOSX { .. }
+        );
+        if is_apple && self.binary_format != BinaryFormat::Macho {
+            return false;
+        }
+
+        match self.binary_format {
+            BinaryFormat::Macho => match self.operating_system {
+                OperatingSystem::Unknown | OperatingSystem::None_ => true,
+                _ => is_apple,
+            },
+            BinaryFormat::Coff => matches!(
+                self.operating_system,
+                OperatingSystem::Unknown
+                    | OperatingSystem::None_
+                    | OperatingSystem::Uefi
+                    | OperatingSystem::Windows
+            ),
+            _ => true,
+        }
+    }
+
+    fn has_compatible_environment(&self) -> bool {
+        let os = self.operating_system;
+        if os == OperatingSystem::Unknown {
+            return true;
+        }
+        match self.environment {
+            Environment::Msvc => os == OperatingSystem::Windows,
+            Environment::Android | Environment::Androideabi => os == OperatingSystem::Linux,
+            Environment::Macabi => os == OperatingSystem::Ios,
+            Environment::Sim => os == OperatingSystem::Ios,
+            Environment::Kernel => os == OperatingSystem::Linux || os == OperatingSystem::Hermit,
+            Environment::AmdGiz | Environment::AmdGfx(_) => os == OperatingSystem::AmdHsa,
+            Environment::TrustZone => os == OperatingSystem::OpTee,
+            Environment::Sgx => false,
+            _ => true,
+        }
+    }
+
     /// Return a `Triple` with all unknown fields.
     pub fn unknown() -> Self {
         Self {
@@ -175,133 +582,2032 @@ impl Triple {
             binary_format: BinaryFormat::Unknown,
         }
     }
-}
-
-impl fmt::Display for Triple {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let implied_binary_format = default_binary_format(&self);
 
-        write!(f, "{}", self.architecture)?;
-        if self.vendor == Vendor::Unknown
-            && ((self.operating_system == OperatingSystem::Linux
-                && (self.environment == Environment::Android
-                    || self.environment == Environment::Androideabi
-                    || self.environment == Environment::Kernel))
-                || self.operating_system == OperatingSystem::Fuchsia
-                || self.operating_system == OperatingSystem::Wasi
-                || (self.operating_system == OperatingSystem::None_
-                    && (self.architecture == Architecture::Arm(ArmArchitecture::Armebv7r)
-                        || self.architecture == Architecture::Arm(ArmArchitecture::Armv7a)
-                        || self.architecture == Architecture::Arm(ArmArchitecture::Armv7r)
-                        || self.architecture == Architecture::Arm(ArmArchitecture::Thumbv6m)
-                        || self.architecture == Architecture::Arm(ArmArchitecture::Thumbv7em)
-                        || self.architecture == Architecture::Arm(ArmArchitecture::Thumbv7m)
-                        || self.architecture == Architecture::Arm(ArmArchitecture::Thumbv8mBase)
-                        || self.architecture == Architecture::Arm(ArmArchitecture::Thumbv8mMain)
-                        || self.architecture == Architecture::Msp430
-                        || self.architecture == Architecture::X86_64)))
-        {
-            // As a special case, omit the vendor for Android, Fuchsia, Wasi, and sometimes
-            // None_, depending on the hardware architecture. This logic is entirely
-            // ad-hoc, and is just sufficient to handle the current set of recognized
-            // triples.
-            write!(f, "-{}", self.operating_system)?;
+    /// Return a typical triple for `architecture` running `operating_system`,
+    /// filling in the vendor and environment most commonly paired with them
+    /// and the binary format they imply.
+    ///
+    /// Windows gets the `pc` vendor and `msvc` environment, Apple operating
+    /// systems get the `apple` vendor, and Linux gets glibc, with the 64-bit
+    /// ABI on 64-bit MIPS. ARM on Linux gets the hard-float ABI only for
+    /// Armv7-A and later, which always have VFP; older and M-profile ARM get
+    /// the soft-float `gnueabi`.
+    pub fn with_defaults_for_os(
+        architecture: Architecture,
+        operating_system: OperatingSystem,
+    ) -> Self {
+        let vendor = if operating_system == OperatingSystem::Windows {
+            Vendor::Pc
+        } else if operating_system.is_like_darwin() {
+            Vendor::Apple
         } else {
-            write!(f, "-{}-{}", self.vendor, self.operating_system)?;
+            Vendor::Unknown
+        };
+        let environment = match operating_system {
+            OperatingSystem::Windows => Environment::Msvc,
+            OperatingSystem::Linux => match architecture {
+                Architecture::Arm(arm) => match arm.version() {
+                    Some(version) if version.major >= 7 && version.profile == ArmProfile::A => {
+                        Environment::Gnueabihf
+                    }
+                    _ => Environment::Gnueabi,
+                },
+                Architecture::Mips64(_) => Environment::Gnuabi64,
+                _ => Environment::Gnu,
+            },
+            _ => Environment::Unknown,
+        };
+        let mut triple = Self {
+            architecture,
+            vendor,
+            operating_system,
+            environment,
+            ..Self::unknown()
+        };
+        triple.binary_format = default_binary_format(&triple);
+        triple
+    }
+
+    /// Return a copy of this triple with the vendor set to `Unknown`, for
+    /// comparing triples whose vendors don't matter, such as
+    /// `x86_64-pc-windows-msvc` and `x86_64-uwp-windows-msvc`.
+    pub fn without_vendor(&self) -> Self {
+        Self {
+            vendor: Vendor::Unknown,
+            ..self.clone()
         }
-        if self.environment != Environment::Unknown {
-            write!(f, "-{}", self.environment)?;
+    }
+
+    /// Test whether this triple targets Emscripten, either as asm.js or as
+    /// WebAssembly.
+    pub fn is_emscripten(&self) -> bool {
+        self.operating_system == OperatingSystem::Emscripten
+    }
+
+    /// For s390x (z/Architecture) targets, return whether the vector facility
+    /// and its vector ABI are available by default. The baseline s390x CPU
+    /// (z10) predates the vector facility, so this is `Some(false)` for all
+    /// s390x triples; it's `None` for other architectures.
+    pub fn s390x_has_vector(&self) -> Option<bool> {
+        match self.architecture {
+            Architecture::S390x => Some(false),
+            _ => None,
         }
+    }
 
-        if self.binary_format != implied_binary_format {
-            write!(f, "-{}", self.binary_format)?;
+    /// Return a 64-bit hash of this triple's `Display` string.
+    ///
+    /// Unlike the `Hash` implementation, which depends on the `Hasher` in
+    /// use, this is the 64-bit FNV-1a hash of the canonical spelling, so it's
+    /// the same across runs, platforms, and versions of this crate, as long
+    /// as the spelling of the triple doesn't change. Equal triples hash
+    /// equally however they were spelled when parsed, since the canonical
+    /// spelling doesn't depend on that.
+    pub fn stable_hash(&self) -> u64 {
+        let mut hasher = StableHasher(0xcbf2_9ce4_8422_2325);
+        self.write_to(&mut hasher).unwrap();
+        hasher.0
+    }
+
+    /// Test whether this is a wasm target with 64-bit linear memory, which
+    /// requires runtimes to support the memory64 proposal.
+    pub fn is_memory64(&self) -> bool {
+        self.architecture == Architecture::Wasm64
+    }
+
+    /// Test whether this triple targets a GPU, such as NVPTX or AMDGCN, whose
+    /// code is launched as kernels by a host program rather than run as a
+    /// process, so CPU-specific logic doesn't apply.
+    pub fn is_gpu(&self) -> bool {
+        matches!(
+            self.architecture,
+            Architecture::AmdGcn | Architecture::Nvptx64
+        )
+    }
+
+    /// Test if this triple's architecture uses the Thumb instruction set.
+    pub fn is_thumb(&self) -> bool {
+        match self.architecture {
+            Architecture::Arm(arm) => arm.is_thumb(),
+            Architecture::Aarch64(aarch) => aarch.is_thumb(),
+            _ => false,
         }
-        Ok(())
     }
-}
 
-impl FromStr for Triple {
-    type Err = ParseError;
+    /// Test whether this triple has atomic compare-and-swap instructions, as
+    /// in rustc's `atomic_cas` target option, and so whether the
+    /// `compare_exchange` family of `core::sync::atomic` methods is available.
+    ///
+    /// Some small cores, such as Armv6-M and RISC-V without the A extension,
+    /// have atomic loads and stores but no compare-and-swap.
+    pub fn supports_atomic_cas(&self) -> bool {
+        !matches!(
+            self.architecture,
+            Architecture::Arm(ArmArchitecture::Armv6m)
+                | Architecture::Arm(ArmArchitecture::Thumbv6m)
+                | Architecture::Msp430
+                | Architecture::Riscv32(Riscv32Architecture::Riscv32e)
+                | Architecture::Riscv32(Riscv32Architecture::Riscv32em)
+                | Architecture::Riscv32(Riscv32Architecture::Riscv32emc)
+                | Architecture::Riscv32(Riscv32Architecture::Riscv32i)
+                | Architecture::Riscv32(Riscv32Architecture::Riscv32imc)
+        )
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.split('-');
-        let mut result = Self::unknown();
-        let mut current_part;
+    /// Check this triple for combinations of fields which parse but are
+    /// unlikely to be what was intended.
+    ///
+    /// Unlike `try_new`, this is a soft check meant for sanity-checking
+    /// configurations. It flags M-profile ARM architectures with any
+    /// operating system other than `none` or `unknown`, wasm architectures
+    /// with the Mach-O binary format, and msp430 with a 64-bit environment.
+    pub fn validate(&self) -> Result<(), ValidationWarning> {
+        if let Architecture::Arm(arm) = self.architecture {
+            if arm.is_mprofile()
+                && self.operating_system != OperatingSystem::None_
+                && self.operating_system != OperatingSystem::Unknown
+            {
+                return Err(ValidationWarning::MProfileWithOperatingSystem);
+            }
+        }
+        if matches!(
+            self.architecture,
+            Architecture::Wasm32 | Architecture::Wasm64
+        ) && self.binary_format == BinaryFormat::Macho
+        {
+            return Err(ValidationWarning::WasmWithMacho);
+        }
+        if self.architecture == Architecture::Msp430
+            && matches!(
+                self.environment,
+                Environment::Gnuabi64 | Environment::Muslabi64
+            )
+        {
+            return Err(ValidationWarning::Msp430With64BitEnvironment);
+        }
+        Ok(())
+    }
 
-        current_part = parts.next();
-        if let Some(s) = current_part {
-            if let Ok(architecture) = Architecture::from_str(s) {
-                result.architecture = architecture;
-                current_part = parts.next();
-            } else {
-                // Insist that the triple start with a valid architecture.
-                return Err(ParseError::UnrecognizedArchitecture(s.to_owned()));
+    /// Return the floating-point ABI of this triple, or `None` if it isn't
+    /// known.
+    ///
+    /// An environment naming a float ABI, such as `gnueabihf` or `softfloat`,
+    /// decides it, even on architectures which otherwise always have an FPU,
+    /// as with `aarch64-unknown-none-softfloat` for kernels. Otherwise this
+    /// falls back to the architecture's usual ABI, which for 32-bit ARM
+    /// depends on the environment, so it's `None` there.
+    ///
+    /// MIPS is soft-float with the `gnusf` and `muslsf` environments, and,
+    /// following rustc, with uClibc and on bare metal.
+    pub fn float_abi(&self) -> Option<FloatAbi> {
+        if self.environment.is_hard_float() {
+            return Some(FloatAbi::Hard);
+        }
+        if self.environment.is_soft_float() {
+            return Some(FloatAbi::Soft);
+        }
+        if self.is_spe() {
+            return Some(FloatAbi::Spe);
+        }
+        match self.architecture {
+            Architecture::Riscv32(_) | Architecture::Riscv64(_) => match self.riscv_float_abi()? {
+                RiscvFloatAbi::Ilp32 | RiscvFloatAbi::Lp64 => Some(FloatAbi::Soft),
+                _ => Some(FloatAbi::Hard),
+            },
+            Architecture::Msp430 => Some(FloatAbi::Soft),
+            Architecture::Mips32(_) | Architecture::Mips64(_)
+                if self.environment == Environment::Uclibc
+                    || self.operating_system == OperatingSystem::None_ =>
+            {
+                Some(FloatAbi::Soft)
             }
+            Architecture::Aarch64(_)
+            | Architecture::Mips32(_)
+            | Architecture::Mips64(_)
+            | Architecture::Powerpc
+            | Architecture::Powerpc64
+            | Architecture::Powerpc64le
+            | Architecture::S390x
+            | Architecture::Sparc
+            | Architecture::Sparcv8
+            | Architecture::Sparc64
+            | Architecture::Sparcv9
+            | Architecture::Wasm32
+            | Architecture::Wasm64
+            | Architecture::X86_32(_)
+            | Architecture::X86_64 => Some(FloatAbi::Hard),
+            _ => None,
         }
+    }
 
-        let mut has_vendor = false;
-        let mut has_operating_system = false;
-        if let Some(s) = current_part {
-            if let Ok(vendor) = Vendor::from_str(s) {
-                has_vendor = true;
-                result.vendor = vendor;
-                current_part = parts.next();
+    /// Test whether this triple targets a PowerPC core with the Signal
+    /// Processing Engine instead of a classic FPU, as named by the `gnuspe`
+    /// and `spe` environments. These need their own builds of libgcc.
+    pub fn is_spe(&self) -> bool {
+        matches!(self.environment, Environment::Gnuspe | Environment::Spe)
+    }
+
+    /// Test whether code for this triple uses a hardware FPU by default, or
+    /// return `None` if the architecture isn't known.
+    ///
+    /// The `softfloat` environment rules one out, as do 32-bit ARM targets
+    /// with a soft-float ABI and no NEON, apart from Android's and Apple's,
+    /// whose ARMv7 devices all have VFPv3 and NEON, and RISC-V
+    /// without the F or D extensions. Soft-float MIPS targets are built
+    /// without FPU instructions. MSP430 and SBF have no FPU.
+    pub fn has_hardware_fpu(&self) -> Option<bool> {
+        if self.architecture == Architecture::Unknown {
+            return None;
+        }
+        if self.environment == Environment::Softfloat {
+            return Some(false);
+        }
+        Some(match self.architecture {
+            Architecture::Msp430 | Architecture::Sbf => false,
+            Architecture::Mips32(_) | Architecture::Mips64(_) => {
+                self.float_abi() != Some(FloatAbi::Soft)
+            }
+            Architecture::Arm(arm) => {
+                self.environment.is_hard_float()
+                    || self.environment == Environment::Androideabi
+                    || self.operating_system.is_like_darwin()
+                    || arm.has_neon()
             }
+            Architecture::Riscv32(_) | Architecture::Riscv64(_) => !matches!(
+                self.riscv_float_abi()?,
+                RiscvFloatAbi::Ilp32 | RiscvFloatAbi::Ilp32e | RiscvFloatAbi::Lp64
+            ),
+            _ => true,
+        })
+    }
+
+    /// Test whether floating-point operations are emulated in software on
+    /// this triple, so that a soft-float runtime such as compiler-rt's
+    /// builtins or libgcc must be linked.
+    ///
+    /// This follows the float ABI, except that Android's `androideabi`
+    /// passes floats in integer registers but still computes with the FPU.
+    pub fn requires_soft_float_runtime(&self) -> bool {
+        self.float_abi() == Some(FloatAbi::Soft) && self.environment != Environment::Androideabi
+    }
+
+    /// Return the RISC-V ABI implied by this triple's architecture, or `None`
+    /// if it isn't a RISC-V triple.
+    ///
+    /// Floating-point arguments are passed in registers when the architecture
+    /// includes the D extension (`*d`) or only the F extension (`*f`).
+    pub fn riscv_float_abi(&self) -> Option<RiscvFloatAbi> {
+        Some(match self.architecture {
+            Architecture::Riscv32(riscv32) => match riscv32 {
+                Riscv32Architecture::Riscv32
+                | Riscv32Architecture::Riscv32i
+                | Riscv32Architecture::Riscv32imac
+                | Riscv32Architecture::Riscv32imc => RiscvFloatAbi::Ilp32,
+                Riscv32Architecture::Riscv32e
+                | Riscv32Architecture::Riscv32em
+                | Riscv32Architecture::Riscv32emc => RiscvFloatAbi::Ilp32e,
+            },
+            Architecture::Riscv64(riscv64) => match riscv64 {
+                Riscv64Architecture::Riscv64 | Riscv64Architecture::Riscv64imac => {
+                    RiscvFloatAbi::Lp64
+                }
+                Riscv64Architecture::Riscv64gc => RiscvFloatAbi::Lp64d,
+            },
+            _ => return None,
+        })
+    }
+
+    /// Return a minimal rustc target-spec JSON document describing this
+    /// triple, as a starting point for a custom target file.
+    ///
+    /// Only the fields which can be derived from the triple are emitted:
+    /// `llvm-target`, `arch`, `target-pointer-width`, `target-endian`, `os`,
+    /// and `env`. Fields which aren't known are left out, and there's no
+    /// `data-layout`, so the result usually needs to be filled in by hand.
+    pub fn rust_target_json_stub(&self) -> String {
+        let mut fields = Vec::new();
+        fields.push(("llvm-target", self.to_string()));
+        if let Some(arch) = self.rustc_arch() {
+            fields.push(("arch", arch.to_owned()));
+        }
+        if let Ok(width) = self.pointer_width() {
+            fields.push(("target-pointer-width", width.bits().to_string()));
+        }
+        if let Some(endian) = self.rustc_endian() {
+            fields.push(("target-endian", endian.to_owned()));
+        }
+        fields.push(("os", self.rustc_os().to_owned()));
+        let env = self.rustc_env();
+        if !env.is_empty() {
+            fields.push(("env", env.to_owned()));
         }
 
-        if !has_operating_system {
-            if let Some(s) = current_part {
-                if let Ok(operating_system) = OperatingSystem::from_str(s) {
-                    has_operating_system = true;
-                    result.operating_system = operating_system;
-                    current_part = parts.next();
+        let mut json = String::from("{\n");
+        for (i, (key, value)) in fields.iter().enumerate() {
+            json.push_str("  \"");
+            json.push_str(key);
+            json.push_str("\": \"");
+            for c in value.chars() {
+                if c == '"' || c == '\\' {
+                    json.push('\\');
                 }
+                json.push(c);
             }
+            json.push('"');
+            if i + 1 != fields.len() {
+                json.push(',');
+            }
+            json.push('\n');
         }
+        json.push('}');
+        json
+    }
 
-        let mut has_environment = false;
-        if let Some(s) = current_part {
-            if let Ok(environment) = Environment::from_str(s) {
-                has_environment = true;
-                result.environment = environment;
-                current_part = parts.next();
-            }
+    /// Return this triple in the normalized form LLVM expects, as in
+    /// `-mtriple`.
+    ///
+    /// This differs from `Display` in that the vendor is always spelled out,
+    /// RISC-V extension letters and the SPARC V8 name are dropped from the
+    /// architecture since LLVM selects those through target features and CPU
+    /// names, and `thumbv7neon` becomes `armv7`, as rustc passes it. AMD GPU
+    /// versions are CPU names to LLVM, so they're left out too.
+    ///
+    /// Some divergences from what rustc passes remain: rustc adds a minimum
+    /// OS version to Apple targets, as in `x86_64-apple-macosx10.12.0`, and
+    /// spells some targets in their shorter forms.
+    pub fn llvm_target(&self) -> String {
+        use core::fmt::Write;
+
+        let mut s = String::new();
+        match self.architecture {
+            Architecture::Arm(ArmArchitecture::Thumbv7neon) => s.push_str("armv7"),
+            Architecture::Riscv32(_) => s.push_str("riscv32"),
+            Architecture::Riscv64(_) => s.push_str("riscv64"),
+            Architecture::Sparcv8 => s.push_str("sparc"),
+            architecture => write!(s, "{}", architecture).unwrap(),
         }
+        write!(s, "-{}-{}", self.vendor, self.operating_system).unwrap();
+        match self.environment {
+            Environment::Unknown | Environment::AmdGfx(_) => {}
+            environment => write!(s, "-{}", environment).unwrap(),
+        }
+        if self.binary_format != default_binary_format(self) {
+            write!(s, "-{}", self.binary_format).unwrap();
+        }
+        s
+    }
 
-        let mut has_binary_format = false;
-        if let Some(s) = current_part {
-            if let Ok(binary_format) = BinaryFormat::from_str(s) {
-                has_binary_format = true;
-                result.binary_format = binary_format;
-                current_part = parts.next();
+    /// Return this triple as Apple's tools, such as `xcodebuild`, spell it,
+    /// with `arm64` in place of `aarch64`, as in `arm64-apple-darwin`.
+    ///
+    /// Other architectures, and all non-Apple triples, are written as
+    /// `Display` writes them.
+    pub fn apple_triple(&self) -> String {
+        let s = self.to_string();
+        if self.is_apple()
+            && self.architecture == Architecture::Aarch64(Aarch64Architecture::Aarch64)
+        {
+            if let Some(rest) = s.strip_prefix("aarch64") {
+                return String::from("arm64") + rest;
             }
         }
+        s
+    }
 
-        // The binary format is frequently omitted; if that's the case here,
-        // infer it from the other fields.
-        if !has_binary_format {
-            result.binary_format = default_binary_format(&result);
+    /// Return the `target_*` cfg values rustc would set for this triple,
+    /// keyed by cfg name, as Cargo exposes them to build scripts in
+    /// `CARGO_CFG_TARGET_ARCH` and friends.
+    ///
+    /// The map holds `target_arch`, `target_os`, `target_env`,
+    /// `target_pointer_width`, `target_endian`, and `target_family`. Keys
+    /// whose value can't be determined from the triple are left out, except
+    /// `target_env`, which rustc always sets and which is empty when the
+    /// environment doesn't name a C library.
+    pub fn cargo_cfg(&self) -> BTreeMap<&'static str, String> {
+        let mut cfg = BTreeMap::new();
+        if let Some(arch) = self.rustc_arch() {
+            cfg.insert("target_arch", arch.to_owned());
+        }
+        cfg.insert("target_os", self.rustc_os().to_owned());
+        cfg.insert("target_env", self.rustc_env().to_owned());
+        if let Ok(width) = self.pointer_width() {
+            cfg.insert("target_pointer_width", width.bits().to_string());
+        }
+        if let Some(endian) = self.rustc_endian() {
+            cfg.insert("target_endian", endian.to_owned());
+        }
+        if let Some(family) = self.target_family() {
+            cfg.insert("target_family", family.to_owned());
         }
+        cfg
+    }
 
-        if let Some(s) = current_part {
-            Err(
-                if !has_vendor && !has_operating_system && !has_environment && !has_binary_format {
-                    ParseError::UnrecognizedVendor(s.to_owned())
-                } else if !has_operating_system && !has_environment && !has_binary_format {
-                    ParseError::UnrecognizedOperatingSystem(s.to_owned())
-                } else if !has_environment && !has_binary_format {
-                    ParseError::UnrecognizedEnvironment(s.to_owned())
-                } else if !has_binary_format {
-                    ParseError::UnrecognizedBinaryFormat(s.to_owned())
-                } else {
-                    ParseError::UnrecognizedField(s.to_owned())
-                },
-            )
-        } else {
-            Ok(result)
+    /// Return the architecture name rustc uses for this triple, as in
+    /// `target_arch`.
+    pub(crate) fn rustc_arch(&self) -> Option<&'static str> {
+        Some(match self.architecture {
+            Architecture::Unknown => return None,
+            Architecture::Arm(_) => "arm",
+            Architecture::AmdGcn => "amdgpu",
+            Architecture::Aarch64(_) => "aarch64",
+            Architecture::Asmjs => "wasm32",
+            Architecture::Hexagon => "hexagon",
+            Architecture::X86_32(_) => "x86",
+            Architecture::Mips32(_) => "mips",
+            Architecture::Mips64(_) => "mips64",
+            Architecture::Msp430 => "msp430",
+            Architecture::Nvptx64 => "nvptx64",
+            Architecture::Powerpc => "powerpc",
+            Architecture::Powerpc64 | Architecture::Powerpc64le => "powerpc64",
+            Architecture::Riscv32(_) => "riscv32",
+            Architecture::Riscv64(_) => "riscv64",
+            Architecture::S390x => "s390x",
+            Architecture::Sbf => "sbf",
+            Architecture::Sparc | Architecture::Sparcv8 => "sparc",
+            Architecture::Sparc64 | Architecture::Sparcv9 => "sparc64",
+            Architecture::Wasm32 => "wasm32",
+            Architecture::Wasm64 => "wasm64",
+            Architecture::X86_64 => "x86_64",
+        })
+    }
+
+    /// Return the operating system name rustc uses for this triple, as in
+    /// `target_os`.
+    pub(crate) fn rustc_os(&self) -> &'static str {
+        match self.operating_system {
+            OperatingSystem::Unknown => "unknown",
+            OperatingSystem::AmdHsa => "amdhsa",
+            OperatingSystem::Bitrig => "bitrig",
+            OperatingSystem::Cloudabi => "cloudabi",
+            OperatingSystem::Cuda => "cuda",
+            OperatingSystem::Darwin | OperatingSystem::MacOSX { .. } => "macos",
+            OperatingSystem::Dragonfly => "dragonfly",
+            OperatingSystem::Emscripten => "emscripten",
+            OperatingSystem::Freebsd(_) => "freebsd",
+            OperatingSystem::Fuchsia => "fuchsia",
+            OperatingSystem::Haiku => "haiku",
+            OperatingSystem::Hermit => "hermit",
+            OperatingSystem::Illumos => "illumos",
+            OperatingSystem::Ios => "ios",
+            OperatingSystem::L4re => "l4re",
+            OperatingSystem::Linux => "linux",
+            OperatingSystem::Nebulet => "nebulet",
+            OperatingSystem::Netbsd(_) => "netbsd",
+            OperatingSystem::None_ => "none",
+            OperatingSystem::Openbsd(_) => "openbsd",
+            OperatingSystem::OpTee => "optee",
+            OperatingSystem::Psp => "psp",
+            OperatingSystem::Redox => "redox",
+            OperatingSystem::Solana => "solana",
+            OperatingSystem::Solaris => "solaris",
+            OperatingSystem::Uefi => "uefi",
+            OperatingSystem::VxWorks => "vxworks",
+            OperatingSystem::Wasi => "wasi",
+            OperatingSystem::Windows => "windows",
+            OperatingSystem::Custom(name) => name,
         }
     }
-}
+
+    /// Return the endianness name rustc uses for this triple, as in
+    /// `target_endian`.
+    fn rustc_endian(&self) -> Option<&'static str> {
+        self.endianness().ok().map(|endianness| match endianness {
+            Endianness::Little => "little",
+            Endianness::Big => "big",
+        })
+    }
+
+    /// Return the environment name rustc uses for this triple, as in
+    /// `target_env`. This names the C library, so it's empty for
+    /// environments which only describe an ABI.
+    pub(crate) fn rustc_env(&self) -> &'static str {
+        match self.environment {
+            Environment::Gnu
+            | Environment::Gnuabi64
+            | Environment::Gnueabi
+            | Environment::Gnueabihf
+            | Environment::Gnullvm
+            | Environment::Gnuspe
+            | Environment::Gnux32
+            | Environment::GnuIlp32
+            | Environment::Gnusf => "gnu",
+            Environment::Musl
+            | Environment::Musleabi
+            | Environment::Musleabihf
+            | Environment::Muslabi64
+            | Environment::Muslsf => "musl",
+            Environment::Msvc => "msvc",
+            Environment::Uclibc | Environment::Uclibceabi | Environment::Uclibceabihf => "uclibc",
+            Environment::Relibc => "relibc",
+            Environment::Sgx => "sgx",
+            _ if self.operating_system == OperatingSystem::Redox => "relibc",
+            _ => "",
+        }
+    }
+
+    /// Return the C library this triple links against, or `None` if it isn't
+    /// one of the libraries `Libc` describes or isn't known.
+    ///
+    /// This usually follows the environment, but operating systems with only
+    /// one C library, such as Redox with relibc, imply it.
+    pub fn libc(&self) -> Option<Libc> {
+        Some(match self.environment {
+            Environment::Gnu
+            | Environment::Gnuabi64
+            | Environment::Gnueabi
+            | Environment::Gnueabihf
+            | Environment::Gnullvm
+            | Environment::Gnuspe
+            | Environment::Gnux32
+            | Environment::GnuIlp32
+            | Environment::Gnusf
+                if self.operating_system != OperatingSystem::Windows =>
+            {
+                Libc::Glibc
+            }
+            Environment::Musl
+            | Environment::Musleabi
+            | Environment::Musleabihf
+            | Environment::Muslabi64
+            | Environment::Muslsf => Libc::Musl,
+            Environment::Uclibc | Environment::Uclibceabi | Environment::Uclibceabihf => {
+                Libc::Uclibc
+            }
+            Environment::Android | Environment::Androideabi => Libc::Bionic,
+            Environment::Relibc => Libc::Relibc,
+            _ => match self.operating_system {
+                OperatingSystem::Redox => Libc::Relibc,
+                OperatingSystem::Wasi => Libc::Wasi,
+                OperatingSystem::Windows => Libc::Msvcrt,
+                _ => return None,
+            },
+        })
+    }
+
+    /// Test whether this triple targets one of Apple's platforms, either by
+    /// vendor or by operating system.
+    pub fn is_apple(&self) -> bool {
+        self.vendor == Vendor::Apple || self.operating_system.is_like_darwin()
+    }
+
+    /// Return the `(major, minor, patch)` version of the Apple operating
+    /// system this triple names, or `None` if it isn't an Apple triple or
+    /// carries no version, as with `aarch64-apple-darwin`.
+    pub fn apple_os_version(&self) -> Option<(u16, u16, u16)> {
+        match self.operating_system {
+            OperatingSystem::MacOSX {
+                major,
+                minor,
+                patch,
+            } => Some((major, minor, patch)),
+            _ => None,
+        }
+    }
+
+    /// Test whether this triple targets Fuchsia. Fuchsia triples are written
+    /// both with and without a vendor, as in `aarch64-fuchsia` and
+    /// `aarch64-unknown-fuchsia`.
+    pub fn is_fuchsia(&self) -> bool {
+        self.operating_system == OperatingSystem::Fuchsia
+    }
+
+    /// Test whether this triple targets the Hermit unikernel, either as an
+    /// application linked with it, as in `x86_64-unknown-hermit`, or as the
+    /// kernel itself, as in `x86_64-unknown-hermit-kernel`.
+    pub fn is_hermit(&self) -> bool {
+        self.operating_system == OperatingSystem::Hermit
+    }
+
+    /// Test whether this triple targets Solaris or one of its descendants,
+    /// such as illumos. These share most of their runtime conventions, so
+    /// this is usually the right test; compare `operating_system` directly
+    /// to tell them apart.
+    pub fn is_solarish(&self) -> bool {
+        matches!(
+            self.operating_system,
+            OperatingSystem::Illumos | OperatingSystem::Solaris
+        )
+    }
+
+    /// Test whether this triple builds an operating system kernel, with the
+    /// `kernel` environment, as in `x86_64-linux-kernel` and
+    /// `x86_64-unknown-hermit-kernel`.
+    ///
+    /// Kernels have neither `std` nor dynamic linking, and must not rely on
+    /// a red zone or position-independent executables, whatever their
+    /// operating system otherwise supports.
+    pub fn is_kernel_target(&self) -> bool {
+        self.environment == Environment::Kernel
+    }
+
+    /// Test whether Rust's standard library is available for this triple, as
+    /// opposed to only `core` and `alloc`.
+    ///
+    /// Bare wasm targets count as having `std`, though much of it reports
+    /// errors at runtime. Kernel targets never do, even on operating systems
+    /// with a port of `std` such as Hermit. L4Re's port of `std` is
+    /// incomplete, so it conservatively doesn't count, and nothing is known
+    /// about custom operating systems.
+    pub fn has_std(&self) -> bool {
+        if self.is_kernel_target() {
+            return false;
+        }
+        match self.operating_system {
+            OperatingSystem::Bitrig
+            | OperatingSystem::Cloudabi
+            | OperatingSystem::Darwin
+            | OperatingSystem::Dragonfly
+            | OperatingSystem::Emscripten
+            | OperatingSystem::Freebsd(_)
+            | OperatingSystem::Fuchsia
+            | OperatingSystem::Haiku
+            | OperatingSystem::Hermit
+            | OperatingSystem::Illumos
+            | OperatingSystem::Ios
+            | OperatingSystem::Linux
+            | OperatingSystem::MacOSX { .. }
+            | OperatingSystem::Nebulet
+            | OperatingSystem::Netbsd(_)
+            | OperatingSystem::Openbsd(_)
+            | OperatingSystem::OpTee
+            | OperatingSystem::Redox
+            | OperatingSystem::Solaris
+            | OperatingSystem::VxWorks
+            | OperatingSystem::Wasi
+            | OperatingSystem::Windows => true,
+            OperatingSystem::Unknown => {
+                self.environment == Environment::Sgx
+                    || matches!(
+                        self.architecture,
+                        Architecture::Wasm32 | Architecture::Wasm64
+                    )
+            }
+            OperatingSystem::AmdHsa
+            | OperatingSystem::Cuda
+            | OperatingSystem::L4re
+            | OperatingSystem::None_
+            | OperatingSystem::Psp
+            | OperatingSystem::Solana
+            | OperatingSystem::Uefi
+            | OperatingSystem::Custom(_) => false,
+        }
+    }
+
+    /// Test whether this triple targets bare metal, with no operating system
+    /// and no standard library.
+    pub fn is_freestanding(&self) -> bool {
+        matches!(
+            self.operating_system,
+            OperatingSystem::None_ | OperatingSystem::Unknown
+        ) && !self.has_std()
+    }
+
+    /// Test whether this triple supports loading shared libraries at runtime,
+    /// and so whether `dylib` and `cdylib` crates can be built for it.
+    pub fn supports_dynamic_linking(&self) -> bool {
+        !self.is_kernel_target()
+            && matches!(
+                self.operating_system,
+                OperatingSystem::Bitrig
+                    | OperatingSystem::Darwin
+                    | OperatingSystem::Dragonfly
+                    | OperatingSystem::Freebsd(_)
+                    | OperatingSystem::Fuchsia
+                    | OperatingSystem::Haiku
+                    | OperatingSystem::Illumos
+                    | OperatingSystem::Ios
+                    | OperatingSystem::Linux
+                    | OperatingSystem::MacOSX { .. }
+                    | OperatingSystem::Netbsd(_)
+                    | OperatingSystem::Openbsd(_)
+                    | OperatingSystem::Redox
+                    | OperatingSystem::Solaris
+                    | OperatingSystem::VxWorks
+                    | OperatingSystem::Windows
+            )
+    }
+
+    /// Test whether the only kind of shared library this triple can produce
+    /// is a `cdylib`, with no Rust `dylib` support, as in rustc's
+    /// `only_cdylib` target option. This is the case for wasm and NVPTX.
+    pub fn only_cdylib(&self) -> bool {
+        matches!(
+            self.architecture,
+            Architecture::Asmjs
+                | Architecture::Nvptx64
+                | Architecture::Wasm32
+                | Architecture::Wasm64
+        )
+    }
+
+    /// Return the file extension, without the leading dot, of shared
+    /// libraries for this triple, as in `std::env::consts::DLL_EXTENSION`, or
+    /// `None` if it can't produce them.
+    ///
+    /// The BSDs use `so` like Linux. Their system libraries carry a version
+    /// suffix, as in OpenBSD's `libc.so.97.1`, but libraries loaded by name
+    /// at runtime, such as Rust's `cdylib`s, don't.
+    pub fn dll_extension(&self) -> Option<&'static str> {
+        if matches!(
+            self.architecture,
+            Architecture::Wasm32 | Architecture::Wasm64
+        ) {
+            Some("wasm")
+        } else if !self.supports_dynamic_linking() {
+            None
+        } else if self.operating_system.is_like_darwin() {
+            Some("dylib")
+        } else if self.operating_system.is_like_windows() {
+            Some("dll")
+        } else {
+            Some("so")
+        }
+    }
+
+    /// Return the primary `target_family` rustc reports for this triple:
+    /// `"unix"`, `"windows"`, or `"wasm"`, or `None` if it's in no family.
+    pub fn target_family(&self) -> Option<&'static str> {
+        if self.operating_system == OperatingSystem::Windows {
+            Some("windows")
+        } else if self.operating_system.is_like_unix() {
+            Some("unix")
+        } else if self.operating_system.is_like_wasm()
+            || self.architecture == Architecture::Wasm32
+            || self.architecture == Architecture::Wasm64
+        {
+            Some("wasm")
+        } else {
+            None
+        }
+    }
+
+    /// Return the ABI alignment, in bytes, of an integer `bits` wide, or
+    /// `None` if the width isn't one of 8, 16, 32, 64, or 128, or the
+    /// architecture isn't known.
+    ///
+    /// Integers are usually aligned to their size, but some ABIs under-align
+    /// them: MSP430 aligns everything wider than a byte to 2, 32-bit x86
+    /// outside of Windows and UEFI aligns 64-bit integers to 4, and most 32-bit
+    /// architectures, along with s390x, align 128-bit integers to 8. These
+    /// follow the integer alignments in LLVM's data layouts, which depend on
+    /// the architecture rather than the pointer width, so ILP32 environments
+    /// such as `gnux32` align like their 64-bit architecture.
+    pub fn natural_align(&self, bits: u32) -> Option<u64> {
+        let size = match bits {
+            8 | 16 | 32 | 64 | 128 => u64::from(bits / 8),
+            _ => return None,
+        };
+        if size == 1 {
+            return self.architecture.register_width().ok().map(|_| 1);
+        }
+        Some(match self.architecture {
+            Architecture::Unknown => return None,
+            Architecture::Msp430 => 2,
+            Architecture::X86_32(_) => match size {
+                8 if !self.operating_system.is_like_windows() => 4,
+                _ => size,
+            },
+            Architecture::S390x => size.min(8),
+            Architecture::Riscv32(_) | Architecture::Wasm32 => size,
+            _ => match self.architecture.register_width().ok()? {
+                PointerWidth::U64 => size,
+                PointerWidth::U32 | PointerWidth::U16 => size.min(8),
+            },
+        })
+    }
+
+    /// Return the ABI alignment, in bytes, of `i128` and `u128`, or `None` if
+    /// the architecture isn't known.
+    ///
+    /// This follows current rustc and LLVM, which align 128-bit integers to
+    /// 16 on x86 as the System V ABI requires; before LLVM 18 they were
+    /// under-aligned to 8 there, which broke interoperation with C's
+    /// `__int128`.
+    pub fn i128_align(&self) -> Option<u64> {
+        self.natural_align(128)
+    }
+
+    /// Return the alignment, in bytes, the ABI requires of the stack pointer
+    /// at call sites, or `None` if it isn't known.
+    ///
+    /// Hand-written trampolines must keep this. Some ABIs require less
+    /// inside functions; 32-bit ARM, for example, only keeps 4 there. 32-bit
+    /// x86 requires 16 except on Windows, which only requires 4, and so does
+    /// Apple's 32-bit ARM ABI.
+    pub fn call_stack_alignment(&self) -> Option<u64> {
+        Some(match self.architecture {
+            Architecture::X86_32(_) if self.operating_system.is_like_windows() => 4,
+            Architecture::Arm(_) if self.operating_system == OperatingSystem::Ios => 4,
+            Architecture::Riscv32(_) if self.riscv_float_abi() == Some(RiscvFloatAbi::Ilp32e) => 4,
+            Architecture::Msp430 => 2,
+            Architecture::Arm(_)
+            | Architecture::Hexagon
+            | Architecture::Mips32(_)
+            | Architecture::S390x
+            | Architecture::Sparc
+            | Architecture::Sparcv8 => 8,
+            Architecture::X86_32(_)
+            | Architecture::X86_64
+            | Architecture::Aarch64(_)
+            | Architecture::Mips64(_)
+            | Architecture::Powerpc
+            | Architecture::Powerpc64
+            | Architecture::Powerpc64le
+            | Architecture::Riscv32(_)
+            | Architecture::Riscv64(_)
+            | Architecture::Sparc64
+            | Architecture::Sparcv9
+            | Architecture::Wasm32
+            | Architecture::Wasm64 => 16,
+            Architecture::Unknown
+            | Architecture::AmdGcn
+            | Architecture::Asmjs
+            | Architecture::Nvptx64
+            | Architecture::Sbf => return None,
+        })
+    }
+
+    /// Return whether code for this triple keeps frame pointers by default,
+    /// following rustc and Clang.
+    ///
+    /// Apple platforms and Solaris keep them everywhere (Apple's arm64 ABI
+    /// only requires them in non-leaf functions, as does Windows on arm64).
+    /// Everything else may omit them.
+    pub fn default_frame_pointer(&self) -> FramePointer {
+        let is_aarch64 = matches!(self.architecture, Architecture::Aarch64(_));
+        if self.operating_system.is_like_darwin()
+            || (is_aarch64 && self.operating_system == OperatingSystem::Windows)
+        {
+            if is_aarch64 {
+                FramePointer::NonLeaf
+            } else {
+                FramePointer::All
+            }
+        } else if self.is_solarish() {
+            FramePointer::All
+        } else {
+            FramePointer::MayOmit
+        }
+    }
+
+    /// Parse the triple held in the environment variable `var`, such as the
+    /// `TARGET` or `HOST` variables Cargo sets for build scripts.
+    #[cfg(feature = "std")]
+    pub fn from_env(var: &str) -> Result<Self, ParseError> {
+        let value = std::env::var(var)
+            .map_err(|_| ParseError::MissingEnvironmentVariable(var.to_owned()))?;
+        Self::from_str(&value)
+    }
+
+    /// Test whether this triple targets the Universal Windows Platform, whose
+    /// app containers forbid many Win32 APIs. UWP is spelled as a vendor, as
+    /// in `x86_64-uwp-windows-msvc`, and is otherwise treated like `pc`.
+    pub fn is_uwp(&self) -> bool {
+        self.vendor == Vendor::Uwp
+    }
+
+    /// Return the toolchain family this Windows triple is built with, or
+    /// `None` if this isn't a Windows triple or its environment doesn't say.
+    pub fn windows_toolchain(&self) -> Option<WindowsToolchain> {
+        if self.operating_system != OperatingSystem::Windows {
+            return None;
+        }
+        match self.environment {
+            Environment::Msvc => Some(WindowsToolchain::Msvc),
+            Environment::Gnu => Some(WindowsToolchain::Gnu),
+            Environment::Gnullvm => Some(WindowsToolchain::GnuLlvm),
+            _ => None,
+        }
+    }
+
+    /// Return the format of the debug information toolchains emit for this
+    /// triple by default, or `None` if it isn't known.
+    ///
+    /// Windows GNU toolchains emit DWARF into their COFF objects, so only
+    /// MSVC-style targets, including UEFI, use CodeView.
+    pub fn debug_format(&self) -> Option<DebugFormat> {
+        if self.is_like_msvc() {
+            Some(DebugFormat::CodeView)
+        } else if self.binary_format == BinaryFormat::Unknown {
+            None
+        } else {
+            Some(DebugFormat::Dwarf)
+        }
+    }
+
+    /// Return how rustc splits out debug information for this triple by
+    /// default.
+    ///
+    /// Apple targets collect it into dSYM bundles and MSVC-style targets into
+    /// PDB files, so both are `Packed`. Everything else, including Linux,
+    /// keeps it in the artifact.
+    pub fn default_split_debuginfo(&self) -> SplitDebuginfo {
+        if self.operating_system.is_like_darwin() || self.is_like_msvc() {
+            SplitDebuginfo::Packed
+        } else {
+            SplitDebuginfo::Off
+        }
+    }
+
+    /// Return how code for this triple unwinds by default.
+    ///
+    /// Windows uses SEH everywhere except 32-bit x86 with the GNU toolchain,
+    /// which uses DWARF. Emscripten historically used `setjmp`/`longjmp`, as
+    /// did 32-bit ARM iOS. UEFI, wasm outside of Emscripten, GPUs, kernels,
+    /// and bare-metal targets abort. Everything else uses DWARF CFI.
+    pub fn default_unwind(&self) -> UnwindStrategy {
+        if self.operating_system == OperatingSystem::Emscripten {
+            UnwindStrategy::Sjlj
+        } else if self.operating_system == OperatingSystem::Uefi
+            || self.is_freestanding()
+            || self.is_kernel_target()
+            || self.is_gpu()
+            || matches!(
+                self.architecture,
+                Architecture::Wasm32 | Architecture::Wasm64
+            )
+        {
+            UnwindStrategy::Abort
+        } else if self.operating_system == OperatingSystem::Windows {
+            match (self.architecture, self.windows_toolchain()) {
+                (Architecture::X86_32(_), Some(WindowsToolchain::Gnu)) => UnwindStrategy::DwarfCfi,
+                _ => UnwindStrategy::Seh,
+            }
+        } else if self.operating_system == OperatingSystem::Ios {
+            match self.architecture {
+                Architecture::Arm(_) => UnwindStrategy::Sjlj,
+                _ => UnwindStrategy::DwarfCfi,
+            }
+        } else {
+            UnwindStrategy::DwarfCfi
+        }
+    }
+
+    /// Test whether the stack below the stack pointer is a red zone which
+    /// leaf functions may use without adjusting the stack pointer, or `None`
+    /// for architectures other than x86.
+    ///
+    /// The System V x86-64 ABI has a 128-byte red zone, but Windows doesn't,
+    /// and kernel and bare-metal code disables it because interrupts would
+    /// clobber it. 32-bit x86 has no red zone at all.
+    pub fn has_red_zone(&self) -> Option<bool> {
+        match self.architecture {
+            Architecture::X86_32(_) => Some(false),
+            Architecture::X86_64 => Some(
+                !self.operating_system.is_like_windows()
+                    && self.operating_system != OperatingSystem::None_
+                    && !self.is_kernel_target(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Test whether this triple follows MSVC conventions, as Windows does
+    /// unless it uses a GNU toolchain, and as UEFI does.
+    fn is_like_msvc(&self) -> bool {
+        self.operating_system.is_like_windows()
+            && !matches!(
+                self.windows_toolchain(),
+                Some(WindowsToolchain::Gnu) | Some(WindowsToolchain::GnuLlvm)
+            )
+    }
+
+    /// Return the kind of linker this triple links with by default.
+    pub fn default_linker_flavor(&self) -> LinkerFlavor {
+        if self.operating_system.is_like_darwin() {
+            LinkerFlavor::Ld64
+        } else if matches!(
+            self.architecture,
+            Architecture::Wasm32 | Architecture::Wasm64
+        ) {
+            LinkerFlavor::Wasm
+        } else if self.is_like_msvc() {
+            LinkerFlavor::Msvc
+        } else if let OperatingSystem::None_ | OperatingSystem::Unknown = self.operating_system {
+            LinkerFlavor::Ld
+        } else {
+            LinkerFlavor::Gnu
+        }
+    }
+
+    /// Test whether executables for this triple are position-independent by
+    /// default, so that the linker should be passed `-pie`.
+    ///
+    /// This is true for modern Unix-like systems, and false for bare-metal,
+    /// wasm, and kernel targets. Windows images are relocated by the loader
+    /// instead, so it's false there too.
+    pub fn default_pie(&self) -> bool {
+        if self.is_kernel_target() {
+            return false;
+        }
+        matches!(
+            self.operating_system,
+            OperatingSystem::Darwin
+                | OperatingSystem::Dragonfly
+                | OperatingSystem::Freebsd(_)
+                | OperatingSystem::Fuchsia
+                | OperatingSystem::Haiku
+                | OperatingSystem::Hermit
+                | OperatingSystem::Illumos
+                | OperatingSystem::Ios
+                | OperatingSystem::Linux
+                | OperatingSystem::MacOSX { .. }
+                | OperatingSystem::Netbsd(_)
+                | OperatingSystem::Openbsd(_)
+                | OperatingSystem::Redox
+                | OperatingSystem::Solaris
+        )
+    }
+
+    /// Return the thread-local storage model code for this triple uses by
+    /// default, or `None` if the target has no thread-local storage, such as
+    /// bare-metal targets and wasm without threads.
+    ///
+    /// Code is compiled position-independent by default, so this is the most
+    /// general model wherever TLS is available; linkers relax it when they can.
+    pub fn default_tls_model(&self) -> Option<TlsModel> {
+        match self.operating_system {
+            OperatingSystem::Unknown
+            | OperatingSystem::AmdHsa
+            | OperatingSystem::Cuda
+            | OperatingSystem::Emscripten
+            | OperatingSystem::None_
+            | OperatingSystem::Uefi
+            | OperatingSystem::Wasi => None,
+            _ => match self.architecture {
+                Architecture::Unknown
+                | Architecture::Asmjs
+                | Architecture::Wasm32
+                | Architecture::Wasm64 => None,
+                _ => Some(TlsModel::GeneralDynamic),
+            },
+        }
+    }
+
+    /// Test whether thread-local storage is emulated by default on this
+    /// triple, with calls into a runtime library such as libgcc's
+    /// `__emutls_get_address`, rather than accessed natively.
+    ///
+    /// This is the case for MinGW with the GNU toolchain, OpenBSD, and
+    /// Android, which only gained native TLS in API level 29. The TLS model
+    /// from `default_tls_model` doesn't apply where TLS is emulated.
+    pub fn emulated_tls(&self) -> bool {
+        self.windows_toolchain() == Some(WindowsToolchain::Gnu)
+            || matches!(self.operating_system, OperatingSystem::Openbsd(_))
+            || matches!(
+                self.environment,
+                Environment::Android | Environment::Androideabi
+            )
+    }
+
+    /// Return the baseline CPU rustc selects for this triple, as passed to
+    /// LLVM's `-mcpu` or rustc's `-C target-cpu`, or `None` if it isn't
+    /// known.
+    pub fn default_cpu(&self) -> Option<&'static str> {
+        let is_apple = self.operating_system.is_like_darwin();
+        Some(match self.architecture {
+            Architecture::X86_32(_) if is_apple => "yonah",
+            Architecture::X86_32(X86_32Architecture::I386) => "i386",
+            Architecture::X86_32(X86_32Architecture::I586) => "pentium",
+            Architecture::X86_32(X86_32Architecture::I686) => "pentium4",
+            Architecture::X86_64 if is_apple => "core2",
+            Architecture::X86_64 => "x86-64",
+            Architecture::Aarch64(_) if self.operating_system == OperatingSystem::Ios => "apple-a7",
+            Architecture::Aarch64(_) if is_apple => "apple-m1",
+            Architecture::Aarch64(_) | Architecture::Arm(_) => "generic",
+            Architecture::Riscv32(_) => "generic-rv32",
+            Architecture::Riscv64(_) => "generic-rv64",
+            _ => return None,
+        })
+    }
+
+    /// Return the width, in bits, of the widest SIMD registers the baseline
+    /// CPU for this triple has, or `None` if it has no SIMD registers by
+    /// default.
+    ///
+    /// This is 128 for x86 from SSE2 on, NEON on ARM, and VSX on
+    /// little-endian PowerPC64. Wider or optional vector extensions, such as
+    /// AVX, wasm's `simd128`, and the RISC-V V extension, need target
+    /// features beyond the baseline, so they don't count.
+    pub fn default_vector_width(&self) -> Option<u32> {
+        match self.architecture {
+            Architecture::X86_64
+            | Architecture::X86_32(X86_32Architecture::I686)
+            | Architecture::Aarch64(_)
+            | Architecture::Powerpc64le => Some(128),
+            Architecture::X86_32(_) if self.operating_system.is_like_darwin() => Some(128),
+            Architecture::Arm(arm) if arm.has_neon() => Some(128),
+            _ => None,
+        }
+    }
+
+    /// Test whether `s` parses as a triple which displays as exactly `s`
+    /// again. Aliases such as `arm64` parse, but aren't canonical, so they
+    /// don't round-trip.
+    pub fn roundtrips(s: &str) -> bool {
+        Self::from_str(s).is_ok_and(|triple| triple.to_string() == s)
+    }
+
+    /// Parse each of `lines` as a triple, pairing every input with its result
+    /// so callers can report which ones failed and why.
+    pub fn parse_many<'a>(
+        lines: impl Iterator<Item = &'a str>,
+    ) -> Vec<(&'a str, Result<Self, ParseError>)> {
+        lines.map(|line| (line, Self::from_str(line))).collect()
+    }
+
+    /// Parse `s` as a triple on a best-effort basis, accepting fields in any
+    /// order and with any of them but the architecture and operating system
+    /// omitted, as in `linux-x86_64-gnu`.
+    ///
+    /// Strings which parse strictly, such as `wasm32-unknown-unknown` or
+    /// `x86_64-elf`, parse the same way here. Otherwise each dash-separated
+    /// field is classified by trying the architecture, then
+    /// the operating system, then the environment, then the binary format,
+    /// and then the vendor, skipping those already found, so `none` is taken
+    /// as the operating system before the environment. `unknown` and empty
+    /// fields carry no information and are skipped, and custom vendors
+    /// aren't accepted, since any unrecognized field would look like one.
+    /// Fields which fit nowhere are still rejected, as are strings with no
+    /// recognizable architecture or operating system, such as `gnu-x86_64`.
+    pub fn parse_fuzzy(s: &str) -> Result<Self, ParseError> {
+        if let Ok(triple) = Self::from_str(s) {
+            return Ok(triple);
+        }
+
+        let mut architecture = None;
+        let mut vendor = None;
+        let mut operating_system = None;
+        let mut environment = None;
+        let mut binary_format = None;
+        for field in s.split('-') {
+            if field.is_empty() || field == "unknown" {
+                continue;
+            }
+            if architecture.is_none() {
+                if let Ok(parsed) = Architecture::from_str(field) {
+                    architecture = Some(parsed);
+                    continue;
+                }
+            }
+            if operating_system.is_none() {
+                if let Ok(parsed) = OperatingSystem::from_str(field) {
+                    operating_system = Some(parsed);
+                    continue;
+                }
+            }
+            if environment.is_none() {
+                if let Ok(parsed) = Environment::from_str(field) {
+                    environment = Some(parsed);
+                    continue;
+                }
+            }
+            if binary_format.is_none() {
+                if let Ok(parsed) = BinaryFormat::from_str(field) {
+                    binary_format = Some(parsed);
+                    continue;
+                }
+            }
+            if vendor.is_none() {
+                match Vendor::from_str(field) {
+                    Ok(Vendor::Custom(_)) | Err(()) => {}
+                    Ok(parsed) => {
+                        vendor = Some(parsed);
+                        continue;
+                    }
+                }
+            }
+            return Err(ParseError::UnrecognizedField(field.to_owned()));
+        }
+
+        let architecture =
+            architecture.ok_or_else(|| ParseError::UnrecognizedArchitecture(s.to_owned()))?;
+        let operating_system = operating_system
+            .ok_or_else(|| ParseError::UnrecognizedOperatingSystem(s.to_owned()))?;
+        let mut result = Self {
+            architecture,
+            vendor: vendor.unwrap_or(Vendor::Unknown),
+            operating_system,
+            environment: environment.unwrap_or(Environment::Unknown),
+            ..Self::unknown()
+        };
+        match binary_format {
+            Some(binary_format) => result.binary_format = binary_format,
+            None => result.binary_format = default_binary_format(&result),
+        }
+        Ok(result)
+    }
+
+    /// Encode this triple compactly, for caching. `from_bytes` decodes it.
+    ///
+    /// The encoding is a version byte followed by a one-byte tag for each
+    /// field. Each variant's tag is fixed, so encodings stay valid in later
+    /// versions of this crate. The versions in operating systems like
+    /// `macosx10.7.0` and `freebsd13.2` and in AMD GPU environments follow
+    /// their tags as little-endian integers. Custom vendors and operating
+    /// systems have no tags, so they're spelled out as zero-terminated
+    /// strings. Unlike the string form, decoding doesn't need to work out
+    /// which field is which, and the binary format is always preserved.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.push(BYTES_VERSION);
+        push_tag(&mut bytes, ARCHITECTURE_TAGS, &self.architecture);
+        push_tag(&mut bytes, VENDOR_TAGS, &self.vendor);
+
+        let versionless = match self.operating_system {
+            OperatingSystem::MacOSX { .. } => OperatingSystem::MacOSX {
+                major: 0,
+                minor: 0,
+                patch: 0,
+            },
+            OperatingSystem::Freebsd(_) => OperatingSystem::Freebsd(None),
+            OperatingSystem::Netbsd(_) => OperatingSystem::Netbsd(None),
+            OperatingSystem::Openbsd(_) => OperatingSystem::Openbsd(None),
+            operating_system => operating_system,
+        };
+        push_tag(&mut bytes, OPERATING_SYSTEM_TAGS, &versionless);
+        match self.operating_system {
+            OperatingSystem::MacOSX {
+                major,
+                minor,
+                patch,
+            } => {
+                for part in [major, minor, patch].iter() {
+                    bytes.extend_from_slice(&part.to_le_bytes());
+                }
+            }
+            OperatingSystem::Freebsd(version)
+            | OperatingSystem::Netbsd(version)
+            | OperatingSystem::Openbsd(version) => push_os_version(&mut bytes, version),
+            _ => {}
+        }
+
+        match self.environment {
+            Environment::AmdGfx(AmdGfxVersion(gfx)) => {
+                push_tag(
+                    &mut bytes,
+                    ENVIRONMENT_TAGS,
+                    &Environment::AmdGfx(AmdGfxVersion(0)),
+                );
+                bytes.extend_from_slice(&gfx.to_le_bytes());
+            }
+            environment => push_tag(&mut bytes, ENVIRONMENT_TAGS, &environment),
+        }
+
+        push_tag(&mut bytes, BINARY_FORMAT_TAGS, &self.binary_format);
+        bytes
+    }
+
+    /// Decode a triple encoded by `to_bytes`. A triple with a custom
+    /// operating system only decodes once that operating system has been
+    /// registered with `register_custom_os`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParseError> {
+        let mut rest = match bytes {
+            [BYTES_VERSION, rest @ ..] => rest,
+            _ => return Err(ParseError::InvalidBytes),
+        };
+        let architecture = take_tagged(
+            &mut rest,
+            ARCHITECTURE_TAGS,
+            ParseError::UnrecognizedArchitecture,
+        )?;
+        let vendor = take_tagged(&mut rest, VENDOR_TAGS, ParseError::UnrecognizedVendor)?;
+        let operating_system = match take_tagged(
+            &mut rest,
+            OPERATING_SYSTEM_TAGS,
+            ParseError::UnrecognizedOperatingSystem,
+        )? {
+            OperatingSystem::MacOSX { .. } => OperatingSystem::MacOSX {
+                major: take_u16(&mut rest)?,
+                minor: take_u16(&mut rest)?,
+                patch: take_u16(&mut rest)?,
+            },
+            OperatingSystem::Freebsd(_) => OperatingSystem::Freebsd(take_os_version(&mut rest)?),
+            OperatingSystem::Netbsd(_) => OperatingSystem::Netbsd(take_os_version(&mut rest)?),
+            OperatingSystem::Openbsd(_) => OperatingSystem::Openbsd(take_os_version(&mut rest)?),
+            operating_system => operating_system,
+        };
+        let environment = match take_tagged(
+            &mut rest,
+            ENVIRONMENT_TAGS,
+            ParseError::UnrecognizedEnvironment,
+        )? {
+            Environment::AmdGfx(_) => Environment::AmdGfx(AmdGfxVersion(take_u16(&mut rest)?)),
+            environment => environment,
+        };
+        let binary_format = take_tagged(
+            &mut rest,
+            BINARY_FORMAT_TAGS,
+            ParseError::UnrecognizedBinaryFormat,
+        )?;
+        if !rest.is_empty() {
+            return Err(ParseError::InvalidBytes);
+        }
+
+        Ok(Self {
+            architecture,
+            vendor,
+            operating_system,
+            environment,
+            binary_format,
+        })
+    }
+
+    /// Return an iterator over the triples this crate is known to parse and
+    /// display back in the same form, in sorted order. There are
+    /// `KNOWN_TRIPLE_COUNT` of them.
+    pub fn known_triples() -> impl Iterator<Item = &'static str> {
+        KNOWN_TRIPLES.iter().cloned()
+    }
+
+    /// Return the triples `known_triples` yields as a sorted slice, which
+    /// can be binary searched without allocating.
+    pub fn known_triples_sorted() -> &'static [&'static str] {
+        KNOWN_TRIPLES
+    }
+
+    /// Test whether `s` is exactly one of the triples `known_triples`
+    /// yields, using a binary search.
+    pub fn is_known(s: &str) -> bool {
+        KNOWN_TRIPLES.binary_search(&s).is_ok()
+    }
+
+    /// Return the known triples grouped by architecture family, for building
+    /// target matrices. Within each group, triples are in the order
+    /// `known_triples` yields them.
+    pub fn known_grouped_by_family() -> BTreeMap<ArchitectureFamily, Vec<&'static str>> {
+        let mut groups = BTreeMap::new();
+        for known in Self::known_triples() {
+            let family = Self::from_str(known)
+                .ok()
+                .and_then(|triple| triple.architecture.family());
+            if let Some(family) = family {
+                groups.entry(family).or_insert_with(Vec::new).push(known);
+            }
+        }
+        groups
+    }
+
+    /// Return the known triple closest to `s` by edit distance, for use in
+    /// "did you mean" messages when `s` fails to parse.
+    ///
+    /// Returns `None` if no known triple is within a few edits of `s`, or if
+    /// `s` is itself a known triple.
+    pub fn suggest(s: &str) -> Option<&'static str> {
+        const MAX_DISTANCE: usize = 3;
+
+        if Self::is_known(s) {
+            return None;
+        }
+        let mut best = None;
+        let mut best_distance = MAX_DISTANCE + 1;
+        for known in Self::known_triples() {
+            let distance = levenshtein(s, known);
+            if distance < best_distance {
+                best = Some(known);
+                best_distance = distance;
+            }
+        }
+        best
+    }
+
+    /// Return the canonical string form of one field of this triple, or
+    /// `None` if `Display` leaves that field out, as it does for an omitted
+    /// vendor, an unknown environment, or an implied binary format.
+    pub fn field_str(&self, field: TripleField) -> Option<String> {
+        match field {
+            TripleField::Architecture => Some(self.architecture.to_string()),
+            TripleField::Vendor => {
+                if self.omits_vendor(None) {
+                    None
+                } else {
+                    Some(self.vendor.to_string())
+                }
+            }
+            TripleField::OperatingSystem => Some(self.operating_system.to_string()),
+            TripleField::Environment => {
+                if self.environment == Environment::Unknown {
+                    None
+                } else {
+                    Some(self.environment.to_string())
+                }
+            }
+            TripleField::BinaryFormat => {
+                if self.shows_binary_format(false) {
+                    Some(self.binary_format.to_string())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Write this triple's string form, as `Display` produces it, to `f`.
+    /// This doesn't allocate, so it can fill a fixed-size buffer in code
+    /// which can't use `alloc`.
+    pub fn write_to<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        self.write_spelled(f, None, true, false)
+    }
+
+    /// Write this triple's string form to `f`, printing or leaving out an
+    /// unknown vendor as `vendor_explicit` says, if it's known, leaving out
+    /// an unknown operating system unless `operating_system_explicit` is set,
+    /// and printing the binary format if `binary_format_explicit` is set.
+    fn write_spelled<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        vendor_explicit: Option<bool>,
+        operating_system_explicit: bool,
+        binary_format_explicit: bool,
+    ) -> fmt::Result {
+        write!(f, "{}", self.architecture)?;
+        if !self.omits_vendor(vendor_explicit) {
+            write!(f, "-{}", self.vendor)?;
+        }
+        if operating_system_explicit || self.operating_system != OperatingSystem::Unknown {
+            write!(f, "-{}", self.operating_system)?;
+        }
+        if let Environment::AmdGfx(_) = self.environment {
+            write!(f, "--{}", self.environment)?;
+        } else if self.environment != Environment::Unknown {
+            write!(f, "-{}", self.environment)?;
+        }
+
+        if self.shows_binary_format(binary_format_explicit) {
+            write!(f, "-{}", self.binary_format)?;
+        }
+        Ok(())
+    }
+
+    /// Test whether `Display` should print the binary format field, given
+    /// whether it was spelled out. Otherwise it's printed only when it
+    /// differs from the one the other fields imply.
+    fn shows_binary_format(&self, binary_format_explicit: bool) -> bool {
+        binary_format_explicit || self.binary_format != default_binary_format(self)
+    }
+
+    /// Test whether `Display` should leave out the vendor field, given
+    /// whether it was spelled out, if that's known.
+    fn omits_vendor(&self, vendor_explicit: Option<bool>) -> bool {
+        if self.vendor != Vendor::Unknown {
+            return false;
+        }
+        if let Some(explicit) = vendor_explicit {
+            return !explicit;
+        }
+
+        // When we don't know how the triple was spelled, omit the vendor for
+        // Android, Fuchsia, Wasi, and sometimes None_, depending on the
+        // hardware architecture. This logic is entirely ad-hoc, and is just
+        // sufficient to handle the current set of recognized triples.
+        (self.operating_system == OperatingSystem::Linux
+            && (self.environment == Environment::Android
+                || self.environment == Environment::Androideabi
+                || self.environment == Environment::Kernel))
+            || self.operating_system == OperatingSystem::Fuchsia
+            || self.operating_system == OperatingSystem::Wasi
+            || (self.operating_system == OperatingSystem::None_
+                && (self.architecture == Architecture::Arm(ArmArchitecture::Armebv7r)
+                    || self.architecture == Architecture::Arm(ArmArchitecture::Armv7a)
+                    || self.architecture == Architecture::Arm(ArmArchitecture::Armv7r)
+                    || self.architecture == Architecture::Arm(ArmArchitecture::Thumbv6m)
+                    || self.architecture == Architecture::Arm(ArmArchitecture::Thumbv7em)
+                    || self.architecture == Architecture::Arm(ArmArchitecture::Thumbv7m)
+                    || self.architecture == Architecture::Arm(ArmArchitecture::Thumbv8mBase)
+                    || self.architecture == Architecture::Arm(ArmArchitecture::Thumbv8mMain)
+                    || self.architecture == Architecture::Arm(ArmArchitecture::Thumbv8_1mMain)
+                    || self.architecture == Architecture::Msp430
+                    || self.architecture == Architecture::X86_64))
+    }
+}
+
+/// Width, fill, alignment, and precision flags are honored, so `{:<30}` pads
+/// a triple for tabular output. Only then is the string form buffered.
+impl fmt::Display for Triple {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.width().is_none() && f.precision().is_none() {
+            return self.write_to(f);
+        }
+        let mut s = String::new();
+        self.write_to(&mut s)?;
+        f.pad(&s)
+    }
+}
+
+/// Fields after the operating system are matched against `Environment`
+/// first and then against `BinaryFormat`, so `x86_64-unknown-none-elf` sets
+/// `binary_format` while `armv7a-none-eabi` sets `environment`. A field which
+/// names both (only `unknown` does) is taken as the environment.
+///
+/// Any triple may end with an explicit binary format, even one its other
+/// fields already imply, as in `x86_64-unknown-linux-gnu-elf`. `Display`
+/// leaves out an implied binary format, but `SpelledTriple` reproduces it.
+///
+/// The Apple SDK-style `iossimulator` operating system name is normalized to
+/// iOS with the `sim` environment, so `x86_64-apple-iossimulator` parses the
+/// same as `x86_64-apple-ios-sim`, which is how it displays. Only iOS is
+/// handled this way, since there are no watchOS or tvOS operating systems
+/// here; `darwinsimulator` and `macosx10.7.0simulator` are rejected.
+impl FromStr for Triple {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SpelledTriple::from_str(s).map(SpelledTriple::into_triple)
+    }
+}
+
+/// A `Triple` together with how it was spelled in the string it was parsed
+/// from, for tools which must print a triple exactly as they received it.
+///
+/// `Triple` displays in a canonical form, which decides on its own whether
+/// to print an unknown vendor or an implied binary format, so
+/// `x86_64-linux` and `x86_64-unknown-linux` parse to equal triples which
+/// display the same way. As `SpelledTriple`s, they compare unequal and each
+/// displays as it was written.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SpelledTriple {
+    triple: Triple,
+    vendor_explicit: bool,
+    operating_system_explicit: bool,
+    binary_format_explicit: bool,
+}
+
+impl SpelledTriple {
+    /// Return the parsed triple.
+    pub fn triple(&self) -> &Triple {
+        &self.triple
+    }
+
+    /// Return the parsed triple, discarding its spelling.
+    pub fn into_triple(self) -> Triple {
+        self.triple
+    }
+
+    /// Return whether the vendor field was present in the string this triple
+    /// was parsed from, so that `x86_64-linux` and `x86_64-unknown-linux` can
+    /// be told apart.
+    pub fn vendor_was_explicit(&self) -> bool {
+        self.vendor_explicit
+    }
+
+    /// Return whether the operating system field was present in the string
+    /// this triple was parsed from, so that `x86_64-unknown-unknown-elf` and
+    /// `x86_64-unknown-elf` can be told apart.
+    pub fn operating_system_was_explicit(&self) -> bool {
+        self.operating_system_explicit
+    }
+
+    /// Return whether the binary format field was present in the string this
+    /// triple was parsed from, as in `x86_64-unknown-linux-gnu-elf`.
+    pub fn binary_format_was_explicit(&self) -> bool {
+        self.binary_format_explicit
+    }
+}
+
+impl fmt::Display for SpelledTriple {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.triple.write_spelled(
+            f,
+            Some(self.vendor_explicit),
+            self.operating_system_explicit,
+            self.binary_format_explicit,
+        )
+    }
+}
+
+/// This parses the same strings as `Triple`'s `FromStr` implementation.
+impl FromStr for SpelledTriple {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('-');
+        let mut result = Triple::unknown();
+        let mut current_part;
+
+        current_part = parts.next();
+        if let Some(s) = current_part {
+            if let Ok(architecture) = Architecture::from_str(s) {
+                result.architecture = architecture;
+                current_part = parts.next();
+            } else {
+                // Insist that the triple start with a valid architecture.
+                return Err(ParseError::UnrecognizedArchitecture(s.to_owned()));
+            }
+        }
+
+        let mut has_vendor = false;
+        let mut has_operating_system = false;
+        if let Some(s) = current_part {
+            if let Ok(vendor) = Vendor::from_str(s) {
+                has_vendor = true;
+                result.vendor = vendor;
+                current_part = parts.next();
+            }
+        }
+
+        let mut is_simulator = false;
+        if !has_operating_system {
+            if let Some(s) = current_part {
+                if let Ok(operating_system) = OperatingSystem::from_str(s) {
+                    has_operating_system = true;
+                    result.operating_system = operating_system;
+                    current_part = parts.next();
+                } else if let Some(operating_system) = s
+                    .strip_suffix("simulator")
+                    .and_then(|s| OperatingSystem::from_str(s).ok())
+                    .filter(|os| *os == OperatingSystem::Ios)
+                {
+                    has_operating_system = true;
+                    is_simulator = true;
+                    result.operating_system = operating_system;
+                    current_part = parts.next();
+                }
+            }
+        }
+
+        // The environment takes precedence over the binary format when a
+        // field could be either; see the comment on this impl.
+        let mut has_environment = false;
+        if let Some(s) = current_part {
+            if let Ok(environment) = Environment::from_str(s) {
+                has_environment = true;
+                result.environment = environment;
+                current_part = parts.next();
+            } else if s.is_empty() {
+                // AMD GPU triples put the gfx version after an empty
+                // environment field, as in `amdgcn-amd-amdhsa--gfx900`.
+                let mut lookahead = parts.clone();
+                if let Some(Ok(environment @ Environment::AmdGfx(_))) =
+                    lookahead.next().map(Environment::from_str)
+                {
+                    has_environment = true;
+                    result.environment = environment;
+                    parts = lookahead;
+                    current_part = parts.next();
+                }
+            }
+        }
+
+        if is_simulator && !has_environment {
+            has_environment = true;
+            result.environment = Environment::Sim;
+        }
+
+        let mut has_binary_format = false;
+        if let Some(s) = current_part {
+            if let Ok(binary_format) = BinaryFormat::from_str(s) {
+                has_binary_format = true;
+                result.binary_format = binary_format;
+                current_part = parts.next();
+            }
+        }
+
+        // The binary format is frequently omitted; if that's the case here,
+        // infer it from the other fields.
+        if !has_binary_format {
+            result.binary_format = default_binary_format(&result);
+        }
+
+        if let Some(s) = current_part {
+            Err(
+                if !has_vendor && !has_operating_system && !has_environment && !has_binary_format {
+                    ParseError::UnrecognizedVendor(s.to_owned())
+                } else if !has_operating_system && !has_environment && !has_binary_format {
+                    ParseError::UnrecognizedOperatingSystem(s.to_owned())
+                } else if !has_environment && !has_binary_format {
+                    ParseError::UnrecognizedEnvironment(s.to_owned())
+                } else if !has_binary_format {
+                    ParseError::UnrecognizedBinaryFormat(s.to_owned())
+                } else {
+                    ParseError::UnrecognizedField(s.to_owned())
+                },
+            )
+        } else {
+            Ok(Self {
+                triple: result,
+                vendor_explicit: has_vendor,
+                operating_system_explicit: has_operating_system,
+                binary_format_explicit: has_binary_format,
+            })
+        }
+    }
+}
+
+/// The version byte which starts the encoding produced by `Triple::to_bytes`.
+const BYTES_VERSION: u8 = 1;
+
+/// The tag for a field which `Triple::to_bytes` spells out as a
+/// zero-terminated string, because it has no entry in the tables below.
+const BYTES_INLINE: u8 = 0xff;
+
+// The tables below give each variant its tag in the encoding produced by
+// `Triple::to_bytes`, which is its index in its table. Encodings must keep
+// decoding the same way, so only append to them. Variants carrying version
+// numbers appear once, with zeros for the versions.
+
+const ARCHITECTURE_TAGS: &[Architecture] = &[
+    Architecture::Unknown,
+    Architecture::Arm(ArmArchitecture::Arm),
+    Architecture::Arm(ArmArchitecture::Armeb),
+    Architecture::Arm(ArmArchitecture::Armv4),
+    Architecture::Arm(ArmArchitecture::Armv4t),
+    Architecture::Arm(ArmArchitecture::Armv5t),
+    Architecture::Arm(ArmArchitecture::Armv5te),
+    Architecture::Arm(ArmArchitecture::Armv5tej),
+    Architecture::Arm(ArmArchitecture::Armv6),
+    Architecture::Arm(ArmArchitecture::Armv6j),
+    Architecture::Arm(ArmArchitecture::Armv6k),
+    Architecture::Arm(ArmArchitecture::Armv6z),
+    Architecture::Arm(ArmArchitecture::Armv6kz),
+    Architecture::Arm(ArmArchitecture::Armv6t2),
+    Architecture::Arm(ArmArchitecture::Armv6m),
+    Architecture::Arm(ArmArchitecture::Armv7),
+    Architecture::Arm(ArmArchitecture::Armv7a),
+    Architecture::Arm(ArmArchitecture::Armv7ve),
+    Architecture::Arm(ArmArchitecture::Armv7m),
+    Architecture::Arm(ArmArchitecture::Armv7r),
+    Architecture::Arm(ArmArchitecture::Armv7s),
+    Architecture::Arm(ArmArchitecture::Armv8),
+    Architecture::Arm(ArmArchitecture::Armv8a),
+    Architecture::Arm(ArmArchitecture::Armv8_1a),
+    Architecture::Arm(ArmArchitecture::Armv8_2a),
+    Architecture::Arm(ArmArchitecture::Armv8_3a),
+    Architecture::Arm(ArmArchitecture::Armv8_4a),
+    Architecture::Arm(ArmArchitecture::Armv8_5a),
+    Architecture::Arm(ArmArchitecture::Armv8mBase),
+    Architecture::Arm(ArmArchitecture::Armv8mMain),
+    Architecture::Arm(ArmArchitecture::Armv8_1mMain),
+    Architecture::Arm(ArmArchitecture::Armv8r),
+    Architecture::Arm(ArmArchitecture::Thumbeb),
+    Architecture::Arm(ArmArchitecture::Thumbv6m),
+    Architecture::Arm(ArmArchitecture::Thumbv7a),
+    Architecture::Arm(ArmArchitecture::Thumbv7em),
+    Architecture::Arm(ArmArchitecture::Thumbv7m),
+    Architecture::Arm(ArmArchitecture::Thumbv7neon),
+    Architecture::Arm(ArmArchitecture::Thumbv8mBase),
+    Architecture::Arm(ArmArchitecture::Thumbv8mMain),
+    Architecture::Arm(ArmArchitecture::Thumbv8_1mMain),
+    Architecture::Arm(ArmArchitecture::Armebv7r),
+    Architecture::Aarch64(Aarch64Architecture::Aarch64),
+    Architecture::Aarch64(Aarch64Architecture::Aarch64be),
+    Architecture::AmdGcn,
+    Architecture::Asmjs,
+    Architecture::Hexagon,
+    Architecture::X86_32(X86_32Architecture::I386),
+    Architecture::X86_32(X86_32Architecture::I586),
+    Architecture::X86_32(X86_32Architecture::I686),
+    Architecture::Mips32(Mips32Architecture::Mips),
+    Architecture::Mips32(Mips32Architecture::Mipsel),
+    Architecture::Mips32(Mips32Architecture::Mipsisa32r6),
+    Architecture::Mips32(Mips32Architecture::Mipsisa32r6el),
+    Architecture::Mips64(Mips64Architecture::Mips64),
+    Architecture::Mips64(Mips64Architecture::Mips64el),
+    Architecture::Mips64(Mips64Architecture::Mipsisa64r6),
+    Architecture::Mips64(Mips64Architecture::Mipsisa64r6el),
+    Architecture::Msp430,
+    Architecture::Nvptx64,
+    Architecture::Powerpc,
+    Architecture::Powerpc64,
+    Architecture::Powerpc64le,
+    Architecture::Riscv32(Riscv32Architecture::Riscv32),
+    Architecture::Riscv32(Riscv32Architecture::Riscv32i),
+    Architecture::Riscv32(Riscv32Architecture::Riscv32e),
+    Architecture::Riscv32(Riscv32Architecture::Riscv32em),
+    Architecture::Riscv32(Riscv32Architecture::Riscv32emc),
+    Architecture::Riscv32(Riscv32Architecture::Riscv32imac),
+    Architecture::Riscv32(Riscv32Architecture::Riscv32imc),
+    Architecture::Riscv64(Riscv64Architecture::Riscv64),
+    Architecture::Riscv64(Riscv64Architecture::Riscv64gc),
+    Architecture::Riscv64(Riscv64Architecture::Riscv64imac),
+    Architecture::S390x,
+    Architecture::Sbf,
+    Architecture::Sparc,
+    Architecture::Sparcv8,
+    Architecture::Sparc64,
+    Architecture::Sparcv9,
+    Architecture::Wasm32,
+    Architecture::Wasm64,
+    Architecture::X86_64,
+];
+
+const VENDOR_TAGS: &[Vendor] = &[
+    Vendor::Unknown,
+    Vendor::Amd,
+    Vendor::Apple,
+    Vendor::Buildroot,
+    Vendor::Experimental,
+    Vendor::Fortanix,
+    Vendor::Nvidia,
+    Vendor::Openwrt,
+    Vendor::Pc,
+    Vendor::Rumprun,
+    Vendor::Solana,
+    Vendor::Sun,
+    Vendor::Uwp,
+    Vendor::Wrs,
+];
+
+const OPERATING_SYSTEM_TAGS: &[OperatingSystem] = &[
+    OperatingSystem::Unknown,
+    OperatingSystem::AmdHsa,
+    OperatingSystem::Bitrig,
+    OperatingSystem::Cloudabi,
+    OperatingSystem::Cuda,
+    OperatingSystem::Darwin,
+    OperatingSystem::Dragonfly,
+    OperatingSystem::Emscripten,
+    OperatingSystem::Freebsd(None),
+    OperatingSystem::Fuchsia,
+    OperatingSystem::Haiku,
+    OperatingSystem::Hermit,
+    OperatingSystem::Illumos,
+    OperatingSystem::Ios,
+    OperatingSystem::L4re,
+    OperatingSystem::Linux,
+    OperatingSystem::MacOSX {
+        major: 0,
+        minor: 0,
+        patch: 0,
+    },
+    OperatingSystem::Nebulet,
+    OperatingSystem::Netbsd(None),
+    OperatingSystem::None_,
+    OperatingSystem::Openbsd(None),
+    OperatingSystem::OpTee,
+    OperatingSystem::Psp,
+    OperatingSystem::Redox,
+    OperatingSystem::Solana,
+    OperatingSystem::Solaris,
+    OperatingSystem::Uefi,
+    OperatingSystem::VxWorks,
+    OperatingSystem::Wasi,
+    OperatingSystem::Windows,
+];
+
+const ENVIRONMENT_TAGS: &[Environment] = &[
+    Environment::Unknown,
+    Environment::AmdGiz,
+    Environment::Android,
+    Environment::Androideabi,
+    Environment::Eabi,
+    Environment::Eabihf,
+    Environment::Gnu,
+    Environment::Gnuabi64,
+    Environment::Gnueabi,
+    Environment::Gnueabihf,
+    Environment::Gnullvm,
+    Environment::Gnuspe,
+    Environment::Gnux32,
+    Environment::GnuIlp32,
+    Environment::Gnusf,
+    Environment::Macabi,
+    Environment::Sim,
+    Environment::Musl,
+    Environment::Musleabi,
+    Environment::Musleabihf,
+    Environment::Muslabi64,
+    Environment::Muslsf,
+    Environment::Msvc,
+    Environment::Kernel,
+    Environment::None_,
+    Environment::Uclibc,
+    Environment::Uclibceabi,
+    Environment::Uclibceabihf,
+    Environment::Relibc,
+    Environment::Sgx,
+    Environment::Softfloat,
+    Environment::Spe,
+    Environment::TrustZone,
+    Environment::AmdGfx(AmdGfxVersion(0)),
+];
+
+const BINARY_FORMAT_TAGS: &[BinaryFormat] = &[
+    BinaryFormat::Unknown,
+    BinaryFormat::Elf,
+    BinaryFormat::Coff,
+    BinaryFormat::Macho,
+    BinaryFormat::Wasm,
+    BinaryFormat::WasmComponent,
+];
+
+/// Append the tag of `value` in `tags` to `bytes`, or spell `value` out if
+/// it has no tag.
+fn push_tag<T: PartialEq + fmt::Display>(bytes: &mut Vec<u8>, tags: &[T], value: &T) {
+    match tags.iter().position(|tag| tag == value) {
+        Some(index) => bytes.push(index as u8),
+        None => {
+            bytes.push(BYTES_INLINE);
+            bytes.extend_from_slice(value.to_string().as_bytes());
+            bytes.push(0);
+        }
+    }
+}
+
+/// Append an operating system version to `bytes` as a count of components
+/// followed by each component.
+fn push_os_version(bytes: &mut Vec<u8>, version: Option<OsVersion>) {
+    match version {
+        None => bytes.push(0),
+        Some(OsVersion { major, minor: None }) => {
+            bytes.push(1);
+            bytes.extend_from_slice(&major.to_le_bytes());
+        }
+        Some(OsVersion {
+            major,
+            minor: Some(minor),
+        }) => {
+            bytes.push(2);
+            bytes.extend_from_slice(&major.to_le_bytes());
+            bytes.extend_from_slice(&minor.to_le_bytes());
+        }
+    }
+}
+
+/// Split the first `len` bytes off `bytes`.
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Result<&'a [u8], ParseError> {
+    if bytes.len() < len {
+        return Err(ParseError::InvalidBytes);
+    }
+    let (taken, rest) = bytes.split_at(len);
+    *bytes = rest;
+    Ok(taken)
+}
+
+/// Split a little-endian `u16` off `bytes`.
+fn take_u16(bytes: &mut &[u8]) -> Result<u16, ParseError> {
+    let taken = take(bytes, 2)?;
+    Ok(u16::from_le_bytes([taken[0], taken[1]]))
+}
+
+/// Split a field written by `push_tag` off `bytes`, reporting a field which
+/// was spelled out but doesn't parse with `error`.
+fn take_tagged<T: Clone + FromStr>(
+    bytes: &mut &[u8],
+    tags: &[T],
+    error: fn(String) -> ParseError,
+) -> Result<T, ParseError> {
+    let tag = take(bytes, 1)?[0];
+    if tag != BYTES_INLINE {
+        return tags
+            .get(usize::from(tag))
+            .cloned()
+            .ok_or(ParseError::InvalidBytes);
+    }
+    let len = bytes
+        .iter()
+        .position(|byte| *byte == 0)
+        .ok_or(ParseError::InvalidBytes)?;
+    let name = core::str::from_utf8(take(bytes, len)?).map_err(|_| ParseError::InvalidBytes)?;
+    take(bytes, 1)?;
+    T::from_str(name).map_err(|_| error(name.to_owned()))
+}
+
+/// Split an operating system version written by `push_os_version` off
+/// `bytes`.
+fn take_os_version(bytes: &mut &[u8]) -> Result<Option<OsVersion>, ParseError> {
+    Ok(match take(bytes, 1)?[0] {
+        0 => None,
+        1 => Some(OsVersion {
+            major: take_u16(bytes)?,
+            minor: None,
+        }),
+        2 => Some(OsVersion {
+            major: take_u16(bytes)?,
+            minor: Some(take_u16(bytes)?),
+        }),
+        _ => return Err(ParseError::InvalidBytes),
+    })
+}
+
+/// Compute the number of single-character insertions, deletions, and
+/// substitutions needed to turn `a` into `b`.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// An FNV-1a hasher which consumes formatted output.
+struct StableHasher(u64);
+
+impl fmt::Write for StableHasher {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for byte in s.bytes() {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+        Ok(())
+    }
+}
 
 /// A convenient syntax for triple literals.
 ///
@@ -315,54 +2621,1475 @@ macro_rules! triple {
     };
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Triple::from_str(""),
+            Err(ParseError::UnrecognizedArchitecture("".to_owned()))
+        );
+        assert_eq!(
+            Triple::from_str("foo"),
+            Err(ParseError::UnrecognizedArchitecture("foo".to_owned()))
+        );
+        assert_eq!(
+            Triple::from_str("unknown-unknown-foo"),
+            Err(ParseError::UnrecognizedOperatingSystem("foo".to_owned()))
+        );
+        assert_eq!(
+            Triple::from_str("unknown-unknown-unknown-foo"),
+            Err(ParseError::UnrecognizedEnvironment("foo".to_owned()))
+        );
+        assert_eq!(
+            Triple::from_str("unknown-unknown-unknown-unknown-foo"),
+            Err(ParseError::UnrecognizedBinaryFormat("foo".to_owned()))
+        );
+        assert_eq!(
+            Triple::from_str("unknown-unknown-unknown-unknown-unknown-foo"),
+            Err(ParseError::UnrecognizedField("foo".to_owned()))
+        );
+    }
+
+    #[test]
+    fn defaults() {
+        assert_eq!(
+            Triple::from_str("unknown-unknown-unknown"),
+            Ok(Triple::unknown())
+        );
+        assert_eq!(
+            Triple::from_str("unknown-unknown-unknown-unknown"),
+            Ok(Triple::unknown())
+        );
+        assert_eq!(
+            Triple::from_str("unknown-unknown-unknown-unknown-unknown"),
+            Ok(Triple::unknown())
+        );
+    }
+
+    #[test]
+    fn try_new() {
+        use crate::targets::X86_32Architecture;
+
+        assert_eq!(
+            Triple::try_new(
+                Architecture::X86_32(X86_32Architecture::I686),
+                Vendor::Pc,
+                OperatingSystem::Windows,
+                Environment::Msvc,
+                BinaryFormat::Coff,
+            ),
+            Ok(Triple::from_str("i686-pc-windows-msvc").unwrap())
+        );
+        assert_eq!(
+            Triple::try_new(
+                Architecture::Wasm32,
+                Vendor::Unknown,
+                OperatingSystem::Unknown,
+                Environment::Unknown,
+                BinaryFormat::Macho,
+            ),
+            Err(InconsistentTriple::IncompatibleBinaryFormat(
+                BinaryFormat::Macho
+            ))
+        );
+        assert_eq!(
+            Triple::try_new(
+                Architecture::X86_64,
+                Vendor::Unknown,
+                OperatingSystem::Linux,
+                Environment::Msvc,
+                BinaryFormat::Elf,
+            ),
+            Err(InconsistentTriple::IncompatibleEnvironment(
+                Environment::Msvc
+            ))
+        );
+    }
+
+    #[test]
+    fn vendor_explicit() {
+        let implicit = SpelledTriple::from_str("x86_64-linux").unwrap();
+        let explicit = SpelledTriple::from_str("x86_64-unknown-linux").unwrap();
+        assert!(!implicit.vendor_was_explicit());
+        assert!(explicit.vendor_was_explicit());
+        assert_ne!(implicit, explicit);
+        assert_eq!(implicit.to_string(), "x86_64-linux");
+        assert_eq!(explicit.to_string(), "x86_64-unknown-linux");
+
+        // The triples themselves are equal and display the same way.
+        assert_eq!(implicit.triple(), explicit.triple());
+        assert_eq!(implicit.triple().to_string(), "x86_64-unknown-linux");
+        assert_eq!(explicit.into_triple().to_string(), "x86_64-unknown-linux");
+    }
+
+    #[test]
+    fn binary_format_explicit() {
+        let implicit = SpelledTriple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        let explicit = SpelledTriple::from_str("x86_64-unknown-linux-gnu-elf").unwrap();
+        assert!(!implicit.binary_format_was_explicit());
+        assert!(explicit.binary_format_was_explicit());
+        assert_ne!(implicit, explicit);
+        assert_eq!(implicit.to_string(), "x86_64-unknown-linux-gnu");
+        assert_eq!(explicit.to_string(), "x86_64-unknown-linux-gnu-elf");
+
+        // The triples themselves are equal and display the same way.
+        assert_eq!(implicit.triple(), explicit.triple());
+        let t = explicit.into_triple();
+        assert_eq!(t.environment, Environment::Gnu);
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+        assert_eq!(t.to_string(), "x86_64-unknown-linux-gnu");
+
+        for triple in [
+            "x86_64-unknown-linux-gnu-elf",
+            "aarch64-apple-darwin-macho",
+            "x86_64-pc-windows-msvc-coff",
+        ]
+        .iter()
+        {
+            let spelled = SpelledTriple::from_str(triple).unwrap();
+            assert_eq!(spelled.to_string(), *triple);
+        }
+    }
+
+    #[test]
+    fn operating_system_explicit() {
+        let implicit = SpelledTriple::from_str("x86_64-unknown-elf").unwrap();
+        let explicit = SpelledTriple::from_str("x86_64-unknown-unknown-elf").unwrap();
+        assert!(!implicit.operating_system_was_explicit());
+        assert!(explicit.operating_system_was_explicit());
+        assert_ne!(implicit, explicit);
+        assert_eq!(implicit.triple(), explicit.triple());
+        assert_eq!(implicit.to_string(), "x86_64-unknown-elf");
+        assert_eq!(explicit.to_string(), "x86_64-unknown-unknown-elf");
+
+        let linux = SpelledTriple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert!(linux.operating_system_was_explicit());
+        let elf = SpelledTriple::from_str("x86_64-elf").unwrap();
+        assert!(!elf.operating_system_was_explicit());
+        assert_eq!(elf.to_string(), "x86_64-elf");
+    }
+
+    #[test]
+    fn without_vendor() {
+        let pc = Triple::from_str("x86_64-pc-windows-msvc").unwrap();
+        let uwp = Triple::from_str("x86_64-uwp-windows-msvc").unwrap();
+        assert_ne!(pc, uwp);
+        assert_eq!(pc.without_vendor(), uwp.without_vendor());
+        assert_eq!(pc.without_vendor().vendor, Vendor::Unknown);
+        assert_eq!(
+            pc.without_vendor().to_string(),
+            "x86_64-unknown-windows-msvc"
+        );
+        assert_ne!(
+            pc.without_vendor(),
+            Triple::from_str("x86_64-pc-windows-gnu")
+                .unwrap()
+                .without_vendor()
+        );
+    }
+
+    #[test]
+    fn vendorless_windows() {
+        for (triple, environment) in [
+            ("x86_64-windows-msvc", Environment::Msvc),
+            ("x86_64-windows-gnu", Environment::Gnu),
+            ("aarch64-windows-gnullvm", Environment::Gnullvm),
+        ]
+        .iter()
+        {
+            let spelled = SpelledTriple::from_str(triple).unwrap();
+            assert!(!spelled.vendor_was_explicit(), "{}", triple);
+            assert_eq!(spelled.to_string(), *triple);
+            let t = spelled.into_triple();
+            assert_eq!(t.vendor, Vendor::Unknown, "{}", triple);
+            assert_eq!(t.operating_system, OperatingSystem::Windows, "{}", triple);
+            assert_eq!(t.environment, *environment, "{}", triple);
+            assert_eq!(t.binary_format, BinaryFormat::Coff, "{}", triple);
+        }
+
+        let vendorless = Triple::from_str("x86_64-windows-msvc").unwrap();
+        let pc = Triple::from_str("x86_64-pc-windows-msvc").unwrap();
+        assert_eq!(vendorless, pc.without_vendor());
+
+        // A custom vendor in front of `windows` is still a vendor.
+        let t = Triple::from_str("x86_64-acme-windows-msvc").unwrap();
+        assert_eq!(t.vendor.to_string(), "acme");
+        assert_eq!(t.operating_system, OperatingSystem::Windows);
+        assert_eq!(t.environment, Environment::Msvc);
+    }
+
+    #[test]
+    fn field_str() {
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(
+            t.field_str(TripleField::Architecture).as_deref(),
+            Some("x86_64")
+        );
+        assert_eq!(t.field_str(TripleField::Vendor).as_deref(), Some("unknown"));
+        assert_eq!(
+            t.field_str(TripleField::OperatingSystem).as_deref(),
+            Some("linux")
+        );
+        assert_eq!(
+            t.field_str(TripleField::Environment).as_deref(),
+            Some("gnu")
+        );
+        assert_eq!(t.field_str(TripleField::BinaryFormat), None);
+
+        let t = Triple::from_str("x86_64-fuchsia").unwrap();
+        assert_eq!(t.field_str(TripleField::Vendor), None);
+        assert_eq!(t.field_str(TripleField::Environment), None);
+
+        let t = Triple::from_str("x86_64-unknown-none-elf").unwrap();
+        assert_eq!(
+            t.field_str(TripleField::BinaryFormat).as_deref(),
+            Some("elf")
+        );
+    }
+
+    #[test]
+    fn stable_hash() {
+        let a = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        let b = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        let c = Triple::from_str("aarch64-unknown-linux-gnu").unwrap();
+        assert_eq!(a.stable_hash(), b.stable_hash());
+        assert_ne!(a.stable_hash(), c.stable_hash());
+
+        for (x, y) in [
+            ("x86_64-linux", "x86_64-unknown-linux"),
+            ("x86_64-unknown-linux-gnu-elf", "x86_64-unknown-linux-gnu"),
+        ]
+        .iter()
+        {
+            let x = Triple::from_str(x).unwrap();
+            let y = Triple::from_str(y).unwrap();
+            assert_eq!(x, y);
+            assert_eq!(x.stable_hash(), y.stable_hash(), "{}", x);
+        }
+
+        // The empty string hashes to the FNV-1a offset basis, and "a" to a
+        // published test vector.
+        let mut hasher = StableHasher(0xcbf2_9ce4_8422_2325);
+        fmt::Write::write_str(&mut hasher, "a").unwrap();
+        assert_eq!(hasher.0, 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn riscv_float_abi() {
+        let t = Triple::from_str("riscv64gc-unknown-linux-gnu").unwrap();
+        assert_eq!(t.riscv_float_abi(), Some(RiscvFloatAbi::Lp64d));
+        assert_eq!(t.riscv_float_abi().unwrap().to_string(), "lp64d");
+        let t = Triple::from_str("riscv32imac-unknown-none-elf").unwrap();
+        assert_eq!(t.riscv_float_abi(), Some(RiscvFloatAbi::Ilp32));
+        let t = Triple::from_str("riscv32e-unknown-none-elf").unwrap();
+        assert_eq!(t.riscv_float_abi(), Some(RiscvFloatAbi::Ilp32e));
+        assert_eq!(t.pointer_width(), Ok(PointerWidth::U32));
+        assert_eq!(t.endianness(), Ok(Endianness::Little));
+        assert_eq!(t.llvm_target(), "riscv32-unknown-none-elf");
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(t.riscv_float_abi(), None);
+    }
+
+    #[test]
+    fn rust_target_json_stub() {
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(
+            t.rust_target_json_stub(),
+            "{\n  \"llvm-target\": \"x86_64-unknown-linux-gnu\",\n  \"arch\": \"x86_64\",\n  \"target-pointer-width\": \"64\",\n  \"target-endian\": \"little\",\n  \"os\": \"linux\",\n  \"env\": \"gnu\"\n}"
+        );
+
+        let json = Triple::from_str("thumbv7em-none-eabihf")
+            .unwrap()
+            .rust_target_json_stub();
+        assert!(json.contains("\"arch\": \"arm\""));
+        assert!(json.contains("\"target-pointer-width\": \"32\""));
+        assert!(json.contains("\"os\": \"none\""));
+        assert!(!json.contains("\"env\""));
+    }
+
+    #[test]
+    fn natural_align() {
+        let x86_64 = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        let i686 = Triple::from_str("i686-unknown-linux-gnu").unwrap();
+        let i686_windows = Triple::from_str("i686-pc-windows-msvc").unwrap();
+        let i686_uefi = Triple::from_str("i686-unknown-uefi").unwrap();
+        let x32 = Triple::from_str("x86_64-unknown-linux-gnux32").unwrap();
+        assert_eq!(x86_64.natural_align(64), Some(8));
+        assert_eq!(i686.natural_align(64), Some(4));
+        assert_eq!(i686_windows.natural_align(64), Some(8));
+        assert_eq!(i686_uefi.natural_align(64), Some(8));
+        assert_eq!(x86_64.natural_align(128), Some(16));
+        assert_eq!(x32.natural_align(128), Some(16));
+        assert_eq!(x32.i128_align(), Some(16));
+        assert_eq!(i686.natural_align(32), Some(4));
+        assert_eq!(x86_64.natural_align(24), None);
+        assert_eq!(Triple::unknown().natural_align(32), None);
+    }
+
+    #[test]
+    fn call_stack_alignment() {
+        for (triple, align) in [
+            ("x86_64-unknown-linux-gnu", Some(16)),
+            ("x86_64-pc-windows-msvc", Some(16)),
+            ("x86_64-apple-darwin", Some(16)),
+            ("i686-unknown-linux-gnu", Some(16)),
+            ("i686-pc-windows-msvc", Some(4)),
+            ("aarch64-unknown-linux-gnu", Some(16)),
+            ("armv7-unknown-linux-gnueabihf", Some(8)),
+            ("riscv32e-unknown-none-elf", Some(4)),
+            ("msp430-none-elf", Some(2)),
+            ("nvptx64-nvidia-cuda", None),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert_eq!(t.call_stack_alignment(), *align, "{}", triple);
+        }
+        assert_eq!(Triple::unknown().call_stack_alignment(), None);
+    }
+
+    #[test]
+    fn i128_align() {
+        for (triple, align) in [
+            ("x86_64-unknown-linux-gnu", Some(16)),
+            ("x86_64-pc-windows-msvc", Some(16)),
+            ("aarch64-unknown-linux-gnu", Some(16)),
+            ("aarch64-apple-darwin", Some(16)),
+            ("i686-unknown-linux-gnu", Some(16)),
+            ("armv7-unknown-linux-gnueabihf", Some(8)),
+            ("s390x-unknown-linux-gnu", Some(8)),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert_eq!(t.i128_align(), *align, "{}", triple);
+        }
+        assert_eq!(Triple::unknown().i128_align(), None);
+    }
+
+    #[test]
+    fn endianness() {
+        assert!(Endianness::Little.is_little());
+        assert!(!Endianness::Little.is_big());
+        assert!(Endianness::Big.is_big());
+        assert!(!Endianness::Big.is_little());
+        assert_eq!(Endianness::Little.opposite(), Endianness::Big);
+        assert_eq!(Endianness::Big.opposite(), Endianness::Little);
+        assert_eq!(Endianness::host().opposite().opposite(), Endianness::host());
+    }
+
+    #[test]
+    fn default_frame_pointer() {
+        let t = Triple::from_str("aarch64-apple-darwin").unwrap();
+        assert_eq!(t.default_frame_pointer(), FramePointer::NonLeaf);
+        let t = Triple::from_str("x86_64-apple-darwin").unwrap();
+        assert_eq!(t.default_frame_pointer(), FramePointer::All);
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(t.default_frame_pointer(), FramePointer::MayOmit);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_env() {
+        std::env::set_var("TARGET_LEXICON_TEST_TRIPLE", "aarch64-unknown-linux-gnu");
+        assert_eq!(
+            Triple::from_env("TARGET_LEXICON_TEST_TRIPLE"),
+            Triple::from_str("aarch64-unknown-linux-gnu")
+        );
+        std::env::set_var("TARGET_LEXICON_TEST_TRIPLE", "foo");
+        assert_eq!(
+            Triple::from_env("TARGET_LEXICON_TEST_TRIPLE"),
+            Err(ParseError::UnrecognizedArchitecture("foo".to_owned()))
+        );
+        std::env::remove_var("TARGET_LEXICON_TEST_TRIPLE");
+        assert_eq!(
+            Triple::from_env("TARGET_LEXICON_TEST_TRIPLE"),
+            Err(ParseError::MissingEnvironmentVariable(
+                "TARGET_LEXICON_TEST_TRIPLE".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn windows_toolchain() {
+        let t = Triple::from_str("x86_64-pc-windows-msvc").unwrap();
+        assert_eq!(t.windows_toolchain(), Some(WindowsToolchain::Msvc));
+        let t = Triple::from_str("x86_64-pc-windows-gnu").unwrap();
+        assert_eq!(t.windows_toolchain(), Some(WindowsToolchain::Gnu));
+        let t = Triple::from_str("aarch64-pc-windows-gnullvm").unwrap();
+        assert_eq!(t.windows_toolchain(), Some(WindowsToolchain::GnuLlvm));
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(t.windows_toolchain(), None);
+    }
+
+    #[test]
+    fn default_tls_model() {
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(t.default_tls_model(), Some(TlsModel::GeneralDynamic));
+        let t = Triple::from_str("thumbv7em-none-eabihf").unwrap();
+        assert_eq!(t.default_tls_model(), None);
+        let t = Triple::from_str("wasm32-wasi").unwrap();
+        assert_eq!(t.default_tls_model(), None);
+    }
+
+    #[test]
+    fn default_vector_width() {
+        for (triple, width) in [
+            ("x86_64-unknown-linux-gnu", Some(128)),
+            ("i686-unknown-linux-gnu", Some(128)),
+            ("i586-unknown-linux-gnu", None),
+            ("aarch64-unknown-linux-gnu", Some(128)),
+            ("thumbv7neon-unknown-linux-gnueabihf", Some(128)),
+            ("thumbv7em-none-eabihf", None),
+            ("powerpc64le-unknown-linux-gnu", Some(128)),
+            ("wasm32-unknown-unknown", None),
+            ("msp430-none-elf", None),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert_eq!(t.default_vector_width(), *width, "{}", triple);
+        }
+    }
+
+    #[test]
+    fn emulated_tls() {
+        for (triple, emulated) in [
+            ("x86_64-pc-windows-gnu", true),
+            ("i686-pc-windows-gnu", true),
+            ("aarch64-linux-android", true),
+            ("x86_64-unknown-openbsd", true),
+            ("x86_64-pc-windows-msvc", false),
+            ("x86_64-pc-windows-gnullvm", false),
+            ("x86_64-unknown-linux-gnu", false),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert_eq!(t.emulated_tls(), *emulated, "{}", triple);
+        }
+    }
+
+    #[test]
+    fn default_cpu() {
+        let cpu = |s| Triple::from_str(s).unwrap().default_cpu();
+        assert_eq!(cpu("i586-unknown-linux-gnu"), Some("pentium"));
+        assert_eq!(cpu("i686-unknown-linux-gnu"), Some("pentium4"));
+        assert_eq!(cpu("i686-pc-windows-msvc"), Some("pentium4"));
+        assert_eq!(cpu("i686-apple-darwin"), Some("yonah"));
+        assert_eq!(cpu("x86_64-unknown-linux-gnu"), Some("x86-64"));
+        assert_eq!(cpu("aarch64-unknown-linux-gnu"), Some("generic"));
+        assert_eq!(cpu("armv7-unknown-linux-gnueabihf"), Some("generic"));
+        assert_eq!(cpu("unknown-unknown-unknown"), None);
+    }
+
+    #[test]
+    fn roundtrips() {
+        assert!(Triple::roundtrips("x86_64-unknown-linux-gnu"));
+        assert!(Triple::roundtrips("aarch64-apple-ios"));
+        assert!(!Triple::roundtrips("arm64-apple-ios"));
+        assert!(!Triple::roundtrips("amd64-unknown-linux-gnu"));
+    }
 
     #[test]
-    fn parse_errors() {
+    fn environment_or_binary_format_tail() {
+        let t = Triple::from_str("x86_64-unknown-none-elf").unwrap();
+        assert_eq!(t.operating_system, OperatingSystem::None_);
+        assert_eq!(t.environment, Environment::Unknown);
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+
+        let t = Triple::from_str("aarch64-unknown-none-eabi").unwrap();
+        assert_eq!(t.operating_system, OperatingSystem::None_);
+        assert_eq!(t.environment, Environment::Eabi);
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+
+        let t = Triple::from_str("x86_64-unknown-none-eabi-coff").unwrap();
+        assert_eq!(t.environment, Environment::Eabi);
+        assert_eq!(t.binary_format, BinaryFormat::Coff);
+
+        // `unknown` names both; the environment wins.
+        let t = Triple::from_str("x86_64-unknown-none-unknown-elf").unwrap();
+        assert_eq!(t.environment, Environment::Unknown);
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+    }
+
+    #[test]
+    fn cargo_cfg() {
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        let cfg = t.cargo_cfg();
+        let expected = [
+            ("target_arch", "x86_64"),
+            ("target_endian", "little"),
+            ("target_env", "gnu"),
+            ("target_family", "unix"),
+            ("target_os", "linux"),
+            ("target_pointer_width", "64"),
+        ];
+        assert_eq!(cfg.len(), expected.len());
+        for (key, value) in expected.iter() {
+            assert_eq!(cfg.get(key).map(String::as_str), Some(*value));
+        }
+
+        let cfg = Triple::from_str("thumbv7em-none-eabihf")
+            .unwrap()
+            .cargo_cfg();
+        assert_eq!(cfg.get("target_env").map(String::as_str), Some(""));
+        assert_eq!(cfg.get("target_family"), None);
+    }
+
+    #[test]
+    fn std_and_dynamic_linking() {
+        for triple in [
+            "x86_64-unknown-redox",
+            "x86_64-unknown-haiku",
+            "x86_64-unknown-linux-gnu",
+            "x86_64-pc-windows-msvc",
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert!(t.has_std(), "{}", triple);
+            assert!(t.supports_dynamic_linking(), "{}", triple);
+        }
+
+        let t = Triple::from_str("wasm32-unknown-unknown").unwrap();
+        assert!(t.has_std());
+        assert!(!t.supports_dynamic_linking());
+
+        let t = Triple::from_str("thumbv7em-none-eabihf").unwrap();
+        assert!(!t.has_std());
+        assert!(!t.supports_dynamic_linking());
+    }
+
+    #[test]
+    fn suggest() {
         assert_eq!(
-            Triple::from_str(""),
-            Err(ParseError::UnrecognizedArchitecture("".to_owned()))
+            Triple::suggest("x86_64-unknown-linux-gnuu"),
+            Some("x86_64-unknown-linux-gnu")
         );
         assert_eq!(
-            Triple::from_str("foo"),
-            Err(ParseError::UnrecognizedArchitecture("foo".to_owned()))
+            Triple::suggest("armv7-unknown-linux-gnueabhf"),
+            Some("armv7-unknown-linux-gnueabihf")
         );
+        assert_eq!(Triple::suggest("x86_64-unknown-linux-gnu"), None);
+        assert_eq!(Triple::suggest("completely-unrelated-garbage"), None);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn debug_format() {
+        let t = Triple::from_str("x86_64-pc-windows-msvc").unwrap();
+        assert_eq!(t.debug_format(), Some(DebugFormat::CodeView));
+        let t = Triple::from_str("x86_64-pc-windows-gnu").unwrap();
+        assert_eq!(t.debug_format(), Some(DebugFormat::Dwarf));
+        let t = Triple::from_str("x86_64-unknown-uefi").unwrap();
+        assert_eq!(t.debug_format(), Some(DebugFormat::CodeView));
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(t.debug_format(), Some(DebugFormat::Dwarf));
+        assert_eq!(Triple::unknown().debug_format(), None);
+    }
+
+    #[test]
+    fn default_unwind() {
+        for (triple, unwind) in [
+            ("x86_64-pc-windows-msvc", UnwindStrategy::Seh),
+            ("i686-pc-windows-msvc", UnwindStrategy::Seh),
+            ("x86_64-pc-windows-gnu", UnwindStrategy::Seh),
+            ("i686-pc-windows-gnu", UnwindStrategy::DwarfCfi),
+            ("x86_64-unknown-linux-gnu", UnwindStrategy::DwarfCfi),
+            ("aarch64-apple-darwin", UnwindStrategy::DwarfCfi),
+            ("armv7-apple-ios", UnwindStrategy::Sjlj),
+            ("wasm32-unknown-emscripten", UnwindStrategy::Sjlj),
+            ("wasm32-unknown-unknown", UnwindStrategy::Abort),
+            ("thumbv7em-none-eabihf", UnwindStrategy::Abort),
+            ("x86_64-unknown-uefi", UnwindStrategy::Abort),
+            ("x86_64-linux-kernel", UnwindStrategy::Abort),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert_eq!(t.default_unwind(), *unwind, "{}", triple);
+        }
+    }
+
+    #[test]
+    fn default_split_debuginfo() {
+        for (triple, split) in [
+            ("aarch64-apple-darwin", SplitDebuginfo::Packed),
+            ("aarch64-apple-ios", SplitDebuginfo::Packed),
+            ("x86_64-pc-windows-msvc", SplitDebuginfo::Packed),
+            ("x86_64-pc-windows-gnu", SplitDebuginfo::Off),
+            ("x86_64-unknown-linux-gnu", SplitDebuginfo::Off),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert_eq!(t.default_split_debuginfo(), *split, "{}", triple);
+        }
+    }
+
+    #[test]
+    fn llvm_target() {
+        for (triple, llvm) in [
+            ("x86_64-unknown-linux-gnu", "x86_64-unknown-linux-gnu"),
+            ("x86_64-pc-windows-msvc", "x86_64-pc-windows-msvc"),
+            ("aarch64-linux-android", "aarch64-unknown-linux-android"),
+            ("thumbv7em-none-eabihf", "thumbv7em-unknown-none-eabihf"),
+            ("riscv32imac-unknown-none-elf", "riscv32-unknown-none-elf"),
+            ("riscv64gc-unknown-linux-gnu", "riscv64-unknown-linux-gnu"),
+            (
+                "thumbv7neon-unknown-linux-gnueabihf",
+                "armv7-unknown-linux-gnueabihf",
+            ),
+            ("amdgcn-amd-amdhsa--gfx900", "amdgcn-amd-amdhsa"),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert_eq!(t.llvm_target(), *llvm, "{}", triple);
+        }
+    }
+
+    #[test]
+    fn has_red_zone() {
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(t.has_red_zone(), Some(true));
+        let t = Triple::from_str("x86_64-pc-windows-msvc").unwrap();
+        assert_eq!(t.has_red_zone(), Some(false));
+        let t = Triple::from_str("x86_64-linux-kernel").unwrap();
+        assert_eq!(t.has_red_zone(), Some(false));
+        let t = Triple::from_str("i686-unknown-linux-gnu").unwrap();
+        assert_eq!(t.has_red_zone(), Some(false));
+        let t = Triple::from_str("aarch64-unknown-linux-gnu").unwrap();
+        assert_eq!(t.has_red_zone(), None);
+    }
+
+    #[test]
+    fn parse_fuzzy() {
+        let t = Triple::parse_fuzzy("linux-x86_64-gnu").unwrap();
+        assert_eq!(t.architecture, Architecture::X86_64);
+        assert_eq!(t.vendor, Vendor::Unknown);
+        assert_eq!(t.operating_system, OperatingSystem::Linux);
+        assert_eq!(t.environment, Environment::Gnu);
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+        assert_eq!(t.to_string(), "x86_64-unknown-linux-gnu");
+
+        let t = Triple::parse_fuzzy("msvc-windows-pc-x86_64").unwrap();
+        assert_eq!(t.to_string(), "x86_64-pc-windows-msvc");
+        let t = Triple::parse_fuzzy("none-thumbv7em-eabihf").unwrap();
+        assert_eq!(t.to_string(), "thumbv7em-none-eabihf");
+
+        // Strictly valid triples, including custom vendors and partial
+        // triples without an operating system, parse as usual.
+        for triple in [
+            "x86_64-unknown-linux-gnu",
+            "x86_64-acme-linux",
+            "wasm32-unknown-unknown",
+            "x86_64-elf",
+            "x86_64-unknown-elf",
+        ]
+        .iter()
+        {
+            assert_eq!(
+                Triple::parse_fuzzy(triple),
+                Triple::from_str(triple),
+                "{}",
+                triple
+            );
+        }
+
         assert_eq!(
-            Triple::from_str("unknown-unknown-foo"),
-            Err(ParseError::UnrecognizedOperatingSystem("foo".to_owned()))
+            Triple::parse_fuzzy("linux-acme-x86_64"),
+            Err(ParseError::UnrecognizedField("acme".to_owned()))
         );
         assert_eq!(
-            Triple::from_str("unknown-unknown-unknown-foo"),
-            Err(ParseError::UnrecognizedEnvironment("foo".to_owned()))
+            Triple::parse_fuzzy("x86_64-linux-aarch64"),
+            Err(ParseError::UnrecognizedField("aarch64".to_owned()))
         );
         assert_eq!(
-            Triple::from_str("unknown-unknown-unknown-unknown-foo"),
-            Err(ParseError::UnrecognizedBinaryFormat("foo".to_owned()))
+            Triple::parse_fuzzy("gnu-linux"),
+            Err(ParseError::UnrecognizedArchitecture("gnu-linux".to_owned()))
         );
+
+        // Otherwise, partial triples without an operating system are
+        // rejected.
+        for triple in ["gnu-x86_64", "pc-x86_64"].iter() {
+            assert_eq!(
+                Triple::parse_fuzzy(triple),
+                Err(ParseError::UnrecognizedOperatingSystem(
+                    (*triple).to_owned()
+                )),
+                "{}",
+                triple
+            );
+        }
+    }
+
+    #[test]
+    fn parse_many() {
+        let input = "x86_64-unknown-linux-gnu\nfoo-unknown-linux\naarch64-apple-darwin\nx86_64-unknown-linux-bar";
+        let results = Triple::parse_many(input.lines());
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].0, "x86_64-unknown-linux-gnu");
+        assert!(results[0].1.is_ok());
         assert_eq!(
-            Triple::from_str("unknown-unknown-unknown-unknown-unknown-foo"),
-            Err(ParseError::UnrecognizedField("foo".to_owned()))
+            results[1],
+            (
+                "foo-unknown-linux",
+                Err(ParseError::UnrecognizedArchitecture("foo".to_owned()))
+            )
+        );
+        assert!(results[2].1.is_ok());
+        assert_eq!(
+            results[3].1,
+            Err(ParseError::UnrecognizedEnvironment("bar".to_owned()))
         );
     }
 
     #[test]
-    fn defaults() {
+    fn float_abi() {
+        let t = Triple::from_str("armv7-unknown-linux-gnueabihf").unwrap();
+        assert_eq!(t.float_abi(), Some(FloatAbi::Hard));
+        let t = Triple::from_str("armv7-unknown-linux-gnueabi").unwrap();
+        assert_eq!(t.float_abi(), Some(FloatAbi::Soft));
+        let t = Triple::from_str("thumbv7em-none-eabihf").unwrap();
+        assert_eq!(t.float_abi(), Some(FloatAbi::Hard));
+        let t = Triple::from_str("riscv32imac-unknown-none-elf").unwrap();
+        assert_eq!(t.float_abi(), Some(FloatAbi::Soft));
+        let t = Triple::from_str("riscv64gc-unknown-linux-gnu").unwrap();
+        assert_eq!(t.float_abi(), Some(FloatAbi::Hard));
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(t.float_abi(), Some(FloatAbi::Hard));
+        assert_eq!(Triple::unknown().float_abi(), None);
+
+        assert!(Environment::Musleabihf.is_hard_float());
+        assert!(Environment::Uclibceabihf.is_hard_float());
+        assert!(Environment::Uclibceabi.is_soft_float());
+        let t = Triple::from_str("armv7-unknown-linux-uclibceabihf").unwrap();
+        assert_eq!(t.float_abi(), Some(FloatAbi::Hard));
+        assert_eq!(t.libc(), Some(Libc::Uclibc));
+        assert!(!Environment::Gnuspe.is_hard_float());
+        assert!(!Environment::Gnuspe.is_soft_float());
+        assert!(Environment::Softfloat.is_soft_float());
+        assert!(!Environment::Gnu.is_soft_float());
+    }
+
+    #[test]
+    fn freestanding() {
+        let t = Triple::from_str("aarch64-unknown-none").unwrap();
+        assert_eq!(t.operating_system, OperatingSystem::None_);
+        assert_eq!(t.binary_format, BinaryFormat::Unknown);
+        assert!(t.is_freestanding());
+
+        let t = Triple::from_str("riscv32i-unknown-none-elf").unwrap();
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+        assert!(t.is_freestanding());
+
+        let t = Triple::from_str("thumbv7em-none-eabihf").unwrap();
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+        assert!(t.is_freestanding());
+
+        // Bare wasm has a `std`, albeit a limited one.
+        let t = Triple::from_str("wasm32-unknown-unknown").unwrap();
+        assert_eq!(t.binary_format, BinaryFormat::Wasm);
+        assert!(!t.is_freestanding());
+
+        let t = Triple::from_str("x86_64-fortanix-unknown-sgx").unwrap();
+        assert!(t.has_std());
+        assert!(!t.is_freestanding());
+
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert!(!t.is_freestanding());
+    }
+
+    #[test]
+    fn powerpc64_calling_convention() {
+        let t = Triple::from_str("powerpc64le-unknown-linux-gnu").unwrap();
         assert_eq!(
-            Triple::from_str("unknown-unknown-unknown"),
-            Ok(Triple::unknown())
+            t.default_calling_convention(),
+            Ok(CallingConvention::PowerPc64ElfV2)
         );
+        let t = Triple::from_str("powerpc64-unknown-linux-gnu").unwrap();
         assert_eq!(
-            Triple::from_str("unknown-unknown-unknown-unknown"),
-            Ok(Triple::unknown())
+            t.default_calling_convention(),
+            Ok(CallingConvention::PowerPc64ElfV1)
         );
+        let t = Triple::from_str("powerpc64-unknown-linux-musl").unwrap();
         assert_eq!(
-            Triple::from_str("unknown-unknown-unknown-unknown-unknown"),
-            Ok(Triple::unknown())
+            t.default_calling_convention(),
+            Ok(CallingConvention::PowerPc64ElfV2)
+        );
+        let t = Triple::from_str("powerpc-unknown-linux-gnu").unwrap();
+        assert_eq!(
+            t.default_calling_convention(),
+            Ok(CallingConvention::SystemV)
+        );
+
+        for triple in [
+            "powerpc64-unknown-linux-gnu",
+            "powerpc64le-unknown-linux-gnu",
+            "powerpc64-unknown-freebsd",
+        ]
+        .iter()
+        {
+            assert_eq!(
+                Triple::from_str(triple).unwrap().data_model(),
+                Ok(CDataModel::LP64),
+                "{}",
+                triple
+            );
+        }
+    }
+
+    #[test]
+    fn known_triples_sorted() {
+        let known = Triple::known_triples_sorted();
+        assert!(known.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(known.len(), Triple::known_triples().count());
+        assert_eq!(Triple::known_triples().count(), crate::KNOWN_TRIPLE_COUNT);
+
+        assert!(Triple::is_known("x86_64-unknown-linux-gnu"));
+        assert!(Triple::is_known("aarch64-apple-ios-sim"));
+        assert!(Triple::is_known(known[0]));
+        assert!(Triple::is_known(known[known.len() - 1]));
+        assert!(!Triple::is_known("x86_64-unknown-linux-gnux"));
+        for fixture in crate::targets::ROUNDTRIP_FIXTURES.iter() {
+            assert!(!Triple::is_known(fixture), "{}", fixture);
+        }
+        assert!(!Triple::is_known(""));
+    }
+
+    #[test]
+    fn known_grouped_by_family() {
+        let groups = Triple::known_grouped_by_family();
+        let x86 = &groups[&ArchitectureFamily::X86];
+        assert!(x86.contains(&"i686-unknown-linux-gnu"));
+        assert!(x86.contains(&"x86_64-unknown-linux-gnu"));
+        assert!(!x86.contains(&"aarch64-unknown-linux-gnu"));
+        assert!(groups[&ArchitectureFamily::Arm].contains(&"aarch64-unknown-linux-gnu"));
+        assert_eq!(
+            groups.values().map(Vec::len).sum::<usize>(),
+            Triple::known_triples().count()
+        );
+    }
+
+    #[test]
+    fn apple() {
+        let t = Triple::from_str("x86_64-apple-macosx10.7.0").unwrap();
+        assert!(t.is_apple());
+        assert_eq!(t.apple_os_version(), Some((10, 7, 0)));
+
+        let t = Triple::from_str("aarch64-apple-darwin").unwrap();
+        assert!(t.is_apple());
+        assert_eq!(t.apple_os_version(), None);
+
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert!(!t.is_apple());
+        assert_eq!(t.apple_os_version(), None);
+    }
+
+    #[test]
+    fn has_hardware_fpu() {
+        for (triple, fpu) in [
+            ("x86_64-unknown-linux-gnu", Some(true)),
+            ("aarch64-unknown-linux-gnu", Some(true)),
+            ("aarch64-unknown-none-softfloat", Some(false)),
+            ("armv7-unknown-linux-gnueabihf", Some(true)),
+            ("thumbv7em-none-eabihf", Some(true)),
+            ("thumbv7em-none-eabi", Some(false)),
+            ("thumbv6m-none-eabi", Some(false)),
+            ("armv7-linux-androideabi", Some(true)),
+            ("armv7-apple-ios", Some(true)),
+            ("armv7s-apple-ios", Some(true)),
+            ("riscv64gc-unknown-linux-gnu", Some(true)),
+            ("riscv32imac-unknown-none-elf", Some(false)),
+            ("msp430-none-elf", Some(false)),
+            ("unknown-unknown-unknown", None),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert_eq!(t.has_hardware_fpu(), *fpu, "{}", triple);
+        }
+    }
+
+    #[test]
+    fn requires_soft_float_runtime() {
+        for triple in [
+            "thumbv6m-none-eabi",
+            "msp430-none-elf",
+            "riscv32imac-unknown-none-elf",
+            "arm-unknown-linux-gnueabi",
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert!(t.requires_soft_float_runtime(), "{}", triple);
+        }
+        for triple in [
+            "x86_64-unknown-linux-gnu",
+            "thumbv7em-none-eabihf",
+            "armv7-linux-androideabi",
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert!(!t.requires_soft_float_runtime(), "{}", triple);
+        }
+    }
+
+    #[test]
+    fn uwp() {
+        for (uwp, pc) in [
+            ("aarch64-uwp-windows-msvc", "aarch64-pc-windows-msvc"),
+            ("x86_64-uwp-windows-msvc", "x86_64-pc-windows-msvc"),
+            ("i686-uwp-windows-gnu", "i686-pc-windows-gnu"),
+        ]
+        .iter()
+        {
+            let uwp = Triple::from_str(uwp).unwrap();
+            let pc = Triple::from_str(pc).unwrap();
+            assert!(uwp.is_uwp());
+            assert!(!pc.is_uwp());
+            assert_eq!(uwp.binary_format, BinaryFormat::Coff);
+            assert_eq!(uwp.binary_format, pc.binary_format);
+            assert_eq!(uwp.windows_toolchain(), pc.windows_toolchain());
+            assert_eq!(uwp.debug_format(), pc.debug_format());
+            assert_eq!(
+                uwp.default_calling_convention(),
+                pc.default_calling_convention()
+            );
+        }
+    }
+
+    #[test]
+    fn pointer_width_override() {
+        let t = Triple::from_str("x86_64-unknown-linux-gnux32").unwrap();
+        assert_eq!(t.architecture.pointer_width(), Ok(PointerWidth::U64));
+        assert_eq!(t.architecture.register_width(), Ok(PointerWidth::U64));
+        assert_eq!(t.pointer_width(), Ok(PointerWidth::U32));
+        assert_eq!(t.data_model(), Ok(CDataModel::ILP32));
+
+        let t = Triple::from_str("aarch64-unknown-linux-gnu_ilp32").unwrap();
+        assert_eq!(t.environment, Environment::GnuIlp32);
+        assert_eq!(t.architecture.register_width(), Ok(PointerWidth::U64));
+        assert_eq!(t.pointer_width(), Ok(PointerWidth::U32));
+        assert_eq!(t.data_model(), Ok(CDataModel::ILP32));
+        assert_eq!(t.libc(), Some(Libc::Glibc));
+
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(Environment::Gnu.pointer_width_override(), None);
+        assert_eq!(t.pointer_width(), Ok(PointerWidth::U64));
+        assert_eq!(t.architecture.register_width(), t.pointer_width());
+
+        let t = Triple::from_str("msp430-none-elf").unwrap();
+        assert_eq!(t.architecture.register_width(), Ok(PointerWidth::U16));
+    }
+
+    #[test]
+    fn aarch64_softfloat() {
+        let t = Triple::from_str("aarch64-unknown-none-softfloat").unwrap();
+        assert_eq!(t.environment, Environment::Softfloat);
+        assert_eq!(t.float_abi(), Some(FloatAbi::Soft));
+        assert!(t.requires_soft_float_runtime());
+
+        let t = Triple::from_str("aarch64-unknown-none").unwrap();
+        assert_eq!(t.float_abi(), Some(FloatAbi::Hard));
+        assert!(!t.requires_soft_float_runtime());
+    }
+
+    #[test]
+    fn fuchsia() {
+        for (short, long) in [
+            ("aarch64-fuchsia", "aarch64-unknown-fuchsia"),
+            ("x86_64-fuchsia", "x86_64-unknown-fuchsia"),
+        ]
+        .iter()
+        {
+            let short = Triple::from_str(short).unwrap();
+            let long = Triple::from_str(long).unwrap();
+            assert_eq!(short, long);
+            assert_eq!(short.operating_system, OperatingSystem::Fuchsia);
+            assert_eq!(long.operating_system, OperatingSystem::Fuchsia);
+            assert!(long.is_fuchsia());
+            assert_eq!(long.binary_format, BinaryFormat::Elf);
+            assert!(long.has_std());
+            assert!(long.supports_dynamic_linking());
+        }
+        assert!(!Triple::from_str("x86_64-unknown-linux-gnu")
+            .unwrap()
+            .is_fuchsia());
+    }
+
+    #[test]
+    fn libc() {
+        for (triple, libc) in [
+            ("x86_64-unknown-linux-gnu", Some(Libc::Glibc)),
+            ("armv7-unknown-linux-gnueabihf", Some(Libc::Glibc)),
+            ("x86_64-unknown-linux-musl", Some(Libc::Musl)),
+            ("mips-unknown-linux-uclibc", Some(Libc::Uclibc)),
+            ("aarch64-linux-android", Some(Libc::Bionic)),
+            ("x86_64-pc-windows-msvc", Some(Libc::Msvcrt)),
+            ("x86_64-pc-windows-gnu", Some(Libc::Msvcrt)),
+            ("x86_64-unknown-redox", Some(Libc::Relibc)),
+            ("x86_64-unknown-redox-relibc", Some(Libc::Relibc)),
+            ("wasm32-wasi", Some(Libc::Wasi)),
+            ("x86_64-apple-darwin", None),
+            ("thumbv7em-none-eabihf", None),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert_eq!(t.libc(), *libc, "{}", triple);
+        }
+
+        let t = Triple::from_str("x86_64-unknown-redox").unwrap();
+        assert_eq!(t.cargo_cfg()["target_env"], "relibc");
+        let t = Triple::from_str("x86_64-unknown-redox-relibc").unwrap();
+        assert_eq!(t.environment, Environment::Relibc);
+        assert_eq!(t.to_string(), "x86_64-unknown-redox-relibc");
+    }
+
+    #[test]
+    fn bytes() {
+        for triple in [
+            "x86_64-unknown-linux-gnu",
+            "x86_64-fuchsia",
+            "x86_64-apple-macosx10.7.0",
+            "x86_64-customvendor-linux",
+            "x86_64-none-elf",
+            "amdgcn-amd-amdhsa--gfx900",
+            "x86_64-unknown-freebsd",
+            "x86_64-unknown-freebsd12",
+            "aarch64-unknown-freebsd13.2",
+        ]
+        .iter()
+        .chain(KNOWN_TRIPLES.iter())
+        .chain(crate::targets::ROUNDTRIP_FIXTURES.iter())
+        {
+            let t = Triple::from_str(triple).unwrap();
+            let decoded = Triple::from_bytes(&t.to_bytes()).unwrap();
+            assert_eq!(decoded, t);
+            assert_eq!(decoded.to_string(), *triple);
+        }
+        let t = Triple::unknown();
+        assert_eq!(Triple::from_bytes(&t.to_bytes()), Ok(t));
+
+        // Every tag fits in a byte without colliding with `BYTES_INLINE`,
+        // and names exactly one variant.
+        fn check_tags<T: PartialEq + fmt::Debug>(tags: &[T]) {
+            assert!(tags.len() < usize::from(BYTES_INLINE));
+            for (i, tag) in tags.iter().enumerate() {
+                assert_eq!(tags.iter().position(|t| t == tag), Some(i), "{:?}", tag);
+            }
+        }
+        check_tags(ARCHITECTURE_TAGS);
+        check_tags(VENDOR_TAGS);
+        check_tags(OPERATING_SYSTEM_TAGS);
+        check_tags(ENVIRONMENT_TAGS);
+        check_tags(BINARY_FORMAT_TAGS);
+
+        // Custom vendors are spelled out.
+        let t = Triple::from_str("x86_64-customvendor-linux").unwrap();
+        let bytes = t.to_bytes();
+        assert_eq!(bytes[2], BYTES_INLINE);
+        assert_eq!(&bytes[3..16], b"customvendor\0");
+
+        assert_eq!(Triple::from_bytes(&[]), Err(ParseError::InvalidBytes));
+        let bytes = Triple::from_str("x86_64-unknown-linux-gnu")
+            .unwrap()
+            .to_bytes();
+        assert_eq!(
+            Triple::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(ParseError::InvalidBytes)
+        );
+        let mut bytes = bytes;
+        bytes[1] = 0xfe;
+        assert_eq!(Triple::from_bytes(&bytes), Err(ParseError::InvalidBytes));
+        assert_eq!(
+            Triple::from_bytes(&[BYTES_VERSION, BYTES_INLINE, b'y', 0, 0, 0, 0, 0]),
+            Err(ParseError::UnrecognizedArchitecture("y".to_owned()))
+        );
+    }
+
+    #[test]
+    fn bytes_size() {
+        for triple in [
+            "aarch64-linux-android",
+            "armv7-apple-ios",
+            "x86_64-apple-macosx10.7.0",
+            "amdgcn-amd-amdhsa--gfx900",
+        ]
+        .iter()
+        .chain(KNOWN_TRIPLES.iter())
+        .chain(crate::targets::ROUNDTRIP_FIXTURES.iter())
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert!(t.to_bytes().len() < triple.len(), "{}", triple);
+        }
+        let t = Triple::from_str("aarch64-linux-android").unwrap();
+        assert_eq!(t.to_bytes().len(), 6);
+    }
+
+    #[test]
+    fn default_linker_flavor() {
+        for (triple, flavor) in [
+            ("x86_64-pc-windows-msvc", LinkerFlavor::Msvc),
+            ("x86_64-unknown-uefi", LinkerFlavor::Msvc),
+            ("x86_64-pc-windows-gnu", LinkerFlavor::Gnu),
+            ("x86_64-apple-darwin", LinkerFlavor::Ld64),
+            ("x86_64-apple-macosx10.7.0", LinkerFlavor::Ld64),
+            ("wasm32-unknown-unknown", LinkerFlavor::Wasm),
+            ("wasm32-wasi", LinkerFlavor::Wasm),
+            ("thumbv7em-none-eabihf", LinkerFlavor::Ld),
+            ("x86_64-unknown-linux-gnu", LinkerFlavor::Gnu),
+            ("x86_64-unknown-freebsd", LinkerFlavor::Gnu),
+            ("x86_64-unknown-netbsd", LinkerFlavor::Gnu),
+            ("x86_64-unknown-openbsd", LinkerFlavor::Gnu),
+            ("x86_64-unknown-dragonfly", LinkerFlavor::Gnu),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert_eq!(t.default_linker_flavor(), *flavor, "{}", triple);
+        }
+    }
+
+    #[test]
+    fn dll_extension() {
+        for (triple, extension) in [
+            ("x86_64-unknown-linux-gnu", Some("so")),
+            ("x86_64-unknown-openbsd", Some("so")),
+            ("aarch64-unknown-netbsd", Some("so")),
+            ("x86_64-unknown-freebsd", Some("so")),
+            ("x86_64-apple-darwin", Some("dylib")),
+            ("x86_64-pc-windows-msvc", Some("dll")),
+            ("x86_64-pc-windows-gnu", Some("dll")),
+            ("wasm32-unknown-unknown", Some("wasm")),
+            ("thumbv7em-none-eabihf", None),
+            ("x86_64-unknown-uefi", None),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert_eq!(t.dll_extension(), *extension, "{}", triple);
+        }
+    }
+
+    #[test]
+    fn only_cdylib() {
+        for triple in [
+            "wasm32-unknown-unknown",
+            "wasm32-wasi",
+            "wasm32-unknown-emscripten",
+            "nvptx64-nvidia-cuda",
+        ]
+        .iter()
+        {
+            assert!(
+                Triple::from_str(triple).unwrap().only_cdylib(),
+                "{}",
+                triple
+            );
+        }
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert!(!t.only_cdylib());
+    }
+
+    #[test]
+    fn with_defaults_for_os() {
+        for (architecture, operating_system, expected) in [
+            (
+                Architecture::X86_64,
+                OperatingSystem::Windows,
+                "x86_64-pc-windows-msvc",
+            ),
+            (
+                Architecture::X86_64,
+                OperatingSystem::Linux,
+                "x86_64-unknown-linux-gnu",
+            ),
+            (
+                Architecture::Arm(ArmArchitecture::Armv7),
+                OperatingSystem::Linux,
+                "armv7-unknown-linux-gnueabihf",
+            ),
+            (
+                Architecture::Arm(ArmArchitecture::Armv5te),
+                OperatingSystem::Linux,
+                "armv5te-unknown-linux-gnueabi",
+            ),
+            (
+                Architecture::Arm(ArmArchitecture::Arm),
+                OperatingSystem::Linux,
+                "arm-unknown-linux-gnueabi",
+            ),
+            (
+                Architecture::X86_64,
+                OperatingSystem::Darwin,
+                "x86_64-apple-darwin",
+            ),
+            (
+                Architecture::X86_64,
+                OperatingSystem::Freebsd(None),
+                "x86_64-unknown-freebsd",
+            ),
+        ]
+        .iter()
+        {
+            let t = Triple::with_defaults_for_os(*architecture, *operating_system);
+            assert_eq!(t.to_string(), *expected);
+            assert_eq!(t, Triple::from_str(expected).unwrap());
+        }
+    }
+
+    #[test]
+    fn default_pie() {
+        for triple in [
+            "x86_64-unknown-linux-gnu",
+            "aarch64-linux-android",
+            "x86_64-apple-darwin",
+            "x86_64-unknown-freebsd",
+        ]
+        .iter()
+        {
+            assert!(
+                Triple::from_str(triple).unwrap().default_pie(),
+                "{}",
+                triple
+            );
+        }
+        for triple in [
+            "thumbv7em-none-eabihf",
+            "riscv32imac-unknown-none-elf",
+            "wasm32-unknown-unknown",
+            "x86_64-linux-kernel",
+            "x86_64-pc-windows-msvc",
+        ]
+        .iter()
+        {
+            assert!(
+                !Triple::from_str(triple).unwrap().default_pie(),
+                "{}",
+                triple
+            );
+        }
+    }
+
+    #[test]
+    fn spe() {
+        for triple in ["powerpc-unknown-linux-gnuspe", "powerpc-wrs-vxworks-spe"].iter() {
+            let t = Triple::from_str(triple).unwrap();
+            assert!(t.is_spe(), "{}", triple);
+            assert_eq!(t.float_abi(), Some(FloatAbi::Spe));
+            assert!(!t.requires_soft_float_runtime());
+        }
+        let t = Triple::from_str("powerpc-unknown-linux-gnu").unwrap();
+        assert!(!t.is_spe());
+        assert_eq!(t.float_abi(), Some(FloatAbi::Hard));
+    }
+
+    #[test]
+    fn write_to() {
+        /// A writer into a fixed-size buffer, which fails when it's full.
+        struct Buffer {
+            bytes: [u8; 32],
+            len: usize,
+        }
+
+        impl fmt::Write for Buffer {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.len + s.len();
+                if end > self.bytes.len() {
+                    return Err(fmt::Error);
+                }
+                self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        for triple in [
+            "x86_64-unknown-linux-gnu",
+            "x86_64-apple-macosx10.7.0",
+            "x86_64-customvendor-linux",
+        ]
+        .iter()
+        {
+            let mut buffer = Buffer {
+                bytes: [0; 32],
+                len: 0,
+            };
+            Triple::from_str(triple)
+                .unwrap()
+                .write_to(&mut buffer)
+                .unwrap();
+            assert_eq!(&buffer.bytes[..buffer.len], triple.as_bytes());
+        }
+
+        let mut buffer = Buffer {
+            bytes: [0; 32],
+            len: 0,
+        };
+        let t = Triple::from_str("thumbv7neon-unknown-linux-musleabihf").unwrap();
+        assert_eq!(t.write_to(&mut buffer), Err(fmt::Error));
+    }
+
+    #[test]
+    fn simulator() {
+        let canonical = Triple::from_str("aarch64-apple-ios-sim").unwrap();
+        assert_eq!(canonical.operating_system, OperatingSystem::Ios);
+        assert_eq!(canonical.environment, Environment::Sim);
+        assert_eq!(canonical.binary_format, BinaryFormat::Macho);
+
+        for triple in [
+            "aarch64-apple-iossimulator",
+            "aarch64-apple-iossimulator-sim",
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert_eq!(t, canonical);
+            assert_eq!(t.to_string(), "aarch64-apple-ios-sim");
+        }
+
+        assert!(Triple::from_str("x86_64-unknown-linuxsimulator").is_err());
+        assert!(Triple::from_str("x86_64-apple-darwinsimulator").is_err());
+        assert!(Triple::from_str("x86_64-apple-macosx10.7.0simulator").is_err());
+        assert_eq!(
+            Triple::try_new(
+                Architecture::X86_64,
+                Vendor::Unknown,
+                OperatingSystem::Linux,
+                Environment::Sim,
+                BinaryFormat::Elf,
+            ),
+            Err(InconsistentTriple::IncompatibleEnvironment(
+                Environment::Sim
+            ))
         );
     }
 
+    #[test]
+    fn hermit() {
+        let app = Triple::from_str("x86_64-unknown-hermit").unwrap();
+        assert!(app.is_hermit());
+        assert!(app.has_std());
+        assert!(!app.is_freestanding());
+
+        let kernel = Triple::from_str("x86_64-unknown-hermit-kernel").unwrap();
+        assert!(kernel.is_hermit());
+        assert_eq!(kernel.environment, Environment::Kernel);
+        assert!(!kernel.has_std());
+        assert_eq!(kernel.has_red_zone(), Some(false));
+    }
+
+    #[test]
+    fn kernel_targets() {
+        for triple in ["x86_64-linux-kernel", "x86_64-unknown-hermit-kernel"].iter() {
+            let t = Triple::from_str(triple).unwrap();
+            assert!(t.is_kernel_target(), "{}", triple);
+            assert!(!t.has_std(), "{}", triple);
+            assert!(!t.supports_dynamic_linking(), "{}", triple);
+            assert!(!t.default_pie(), "{}", triple);
+            assert_eq!(t.dll_extension(), None, "{}", triple);
+        }
+        for triple in ["x86_64-unknown-linux-gnu", "x86_64-unknown-hermit"].iter() {
+            let t = Triple::from_str(triple).unwrap();
+            assert!(!t.is_kernel_target(), "{}", triple);
+            assert!(t.has_std(), "{}", triple);
+        }
+
+        assert!(!Triple::from_str("x86_64-unknown-linux-gnu")
+            .unwrap()
+            .is_hermit());
+    }
+
+    #[test]
+    fn atomic_cas() {
+        for (triple, cas) in [
+            ("armv6m-none-eabi", false),
+            ("thumbv6m-none-eabi", false),
+            ("msp430-none-elf", false),
+            ("riscv32imc-unknown-none-elf", false),
+            ("riscv32imac-unknown-none-elf", true),
+            ("thumbv7m-none-eabi", true),
+            ("x86_64-unknown-linux-gnu", true),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert_eq!(t.supports_atomic_cas(), *cas, "{}", triple);
+        }
+    }
+
+    #[test]
+    fn gpu() {
+        for triple in ["nvptx64-nvidia-cuda", "amdgcn-amd-amdhsa"].iter() {
+            let t = Triple::from_str(triple).unwrap();
+            assert!(t.is_gpu(), "{}", triple);
+            assert!(!t.supports_dynamic_linking(), "{}", triple);
+        }
+        assert!(!Triple::from_str("x86_64-unknown-linux-gnu")
+            .unwrap()
+            .is_gpu());
+    }
+
+    #[test]
+    fn apple_triple() {
+        for (triple, apple) in [
+            ("aarch64-apple-darwin", "arm64-apple-darwin"),
+            ("arm64-apple-ios", "arm64-apple-ios"),
+            ("aarch64-apple-ios-sim", "arm64-apple-ios-sim"),
+            ("x86_64-apple-darwin", "x86_64-apple-darwin"),
+            ("aarch64-unknown-linux-gnu", "aarch64-unknown-linux-gnu"),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert_eq!(t.apple_triple(), *apple, "{}", triple);
+        }
+    }
+
+    #[test]
+    fn display_padding() {
+        let t = Triple::from_str("x86_64-fuchsia").unwrap();
+        assert_eq!(alloc::format!("{:<20}|", t), "x86_64-fuchsia      |");
+        assert_eq!(alloc::format!("{:>20}|", t), "      x86_64-fuchsia|");
+        assert_eq!(alloc::format!("{:-^18}", t), "--x86_64-fuchsia--");
+        assert_eq!(alloc::format!("{:.6}", t), "x86_64");
+        assert_eq!(alloc::format!("{:4}", t), "x86_64-fuchsia");
+        assert_eq!(alloc::format!("{}", t), "x86_64-fuchsia");
+    }
+
+    #[test]
+    fn mips_soft_float() {
+        for (triple, abi) in [
+            ("mips-unknown-linux-gnusf", FloatAbi::Soft),
+            ("mipsel-unknown-linux-muslsf", FloatAbi::Soft),
+            ("mips-unknown-linux-uclibc", FloatAbi::Soft),
+            ("mipsel-unknown-none", FloatAbi::Soft),
+            ("mips-unknown-linux-gnu", FloatAbi::Hard),
+            ("mips64el-unknown-linux-gnuabi64", FloatAbi::Hard),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(triple).unwrap();
+            assert_eq!(t.float_abi(), Some(*abi), "{}", triple);
+        }
+
+        let t = Triple::from_str("mips-unknown-linux-gnusf").unwrap();
+        assert_eq!(t.environment, Environment::Gnusf);
+        assert_eq!(t.libc(), Some(Libc::Glibc));
+        assert!(t.requires_soft_float_runtime());
+        assert_eq!(t.has_hardware_fpu(), Some(false));
+        let t = Triple::from_str("mips-unknown-linux-gnu").unwrap();
+        assert_eq!(t.has_hardware_fpu(), Some(true));
+    }
+
     #[test]
     fn unknown_properties() {
         assert_eq!(Triple::unknown().endianness(), Err(()));